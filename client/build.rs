@@ -0,0 +1,17 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    // Captures are decoded dynamically, from the descriptors of the package's
+    // proto files, so that `substreams run -o jsonl` output can be read too.
+    #[cfg(feature = "capture")]
+    {
+        let mut compiler = protox::Compiler::new(["../proto"])?;
+        compiler.include_imports(true).open_file("contract.proto")?;
+
+        let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+        std::fs::write(out_dir.join("contract.bin"), compiler.encode_file_descriptor_set())?;
+        println!("cargo:rerun-if-changed=../proto/contract.proto");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! Conversions of message fields to `alloy` primitives, enabled by the
+//! `alloy` feature.
+
+use alloy_primitives::{Address, B256, U256};
+use anyhow::{anyhow, ensure, Result};
+use std::str::FromStr;
+
+pub fn address(bytes: &[u8]) -> Result<Address> {
+    ensure!(bytes.len() == 20, "address must be 20 bytes, got {}", bytes.len());
+    Ok(Address::from_slice(bytes))
+}
+
+pub fn bytes32(bytes: &[u8]) -> Result<B256> {
+    ensure!(bytes.len() == 32, "bytes32 must be 32 bytes, got {}", bytes.len());
+    Ok(B256::from_slice(bytes))
+}
+
+pub fn uint256(value: &str) -> Result<U256> {
+    U256::from_str_radix(value, 10).map_err(|err| anyhow!("invalid uint256 {:?}: {}", value, err))
+}
+
+pub fn tx_hash(hash: &str) -> Result<B256> {
+    B256::from_str(hash).map_err(|err| anyhow!("invalid transaction hash {:?}: {}", hash, err))
+}
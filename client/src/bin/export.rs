@@ -0,0 +1,187 @@
+//! Exports the `map_events_calls` outputs of a replay log, or of
+//! `substreams run -o jsonl` output, to one CSV or JSON lines file per event
+//! and call type, `<output_dir>/<table>.csv` or `<output_dir>/<table>.jsonl`.
+//! Addresses and bytes are `0x` hex strings, amounts decimal strings and
+//! timestamps RFC 3339 UTC.
+//!
+//! Usage: `export [--start-block <n>] [--stop-block <n>] <csv|jsonl>
+//! <output_dir> [<input>]`, reading stdin when no input file, or `-`, is
+//! given. Like `substreams run`, the range includes its start block and
+//! excludes its stop block.
+
+use anyhow::{bail, Context, Result};
+use my_project_client::capture::{descriptor_pool, read_outputs};
+use my_project_client::tables::{columns, rows, tables, Column, Row};
+use prost_reflect::ReflectMessage;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+const EVENTS_CALLS: &str = "contract.v1.EventsCalls";
+const USAGE: &str = "usage: export [--start-block <n>] [--stop-block <n>] <csv|jsonl> <output_dir> [<input>]";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Csv,
+    Jsonl,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Csv => "csv",
+            Format::Jsonl => "jsonl",
+        }
+    }
+}
+
+/// Quotes a CSV field when it contains a separator, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn write_row(writer: &mut impl Write, format: Format, columns: &[Column], row: &Row) -> Result<()> {
+    match format {
+        Format::Csv => {
+            let fields: Vec<String> = row.cells.iter().map(|cell| csv_field(&cell.to_text())).collect();
+            writeln!(writer, "{}", fields.join(","))?;
+        }
+        Format::Jsonl => {
+            let object: serde_json::Map<String, serde_json::Value> = columns.iter()
+                .zip(&row.cells)
+                .map(|(column, cell)| (column.name.clone(), cell.to_json()))
+                .collect();
+            writeln!(writer, "{}", serde_json::Value::Object(object))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Block range of the outputs to export, from `--start-block` included to
+/// `--stop-block` excluded.
+#[derive(Default)]
+struct BlockRange {
+    start: Option<u64>,
+    stop: Option<u64>,
+}
+
+impl BlockRange {
+    fn contains(&self, block_number: u64) -> bool {
+        self.start.is_none_or(|start| block_number >= start) && self.stop.is_none_or(|stop| block_number < stop)
+    }
+}
+
+/// Splits the `--start-block` and `--stop-block` options from the positional
+/// arguments.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<(BlockRange, Vec<String>)> {
+    let mut range = BlockRange::default();
+    let mut positional = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let bound = match arg.as_str() {
+            "--start-block" => &mut range.start,
+            "--stop-block" => &mut range.stop,
+            _ => {
+                positional.push(arg);
+                continue;
+            }
+        };
+        let value = args.next().with_context(|| format!("missing value for {}", arg))?;
+        *bound = Some(value.parse().with_context(|| format!("invalid block number {:?} for {}", value, arg))?);
+    }
+
+    Ok((range, positional))
+}
+
+fn main() -> Result<()> {
+    let (range, args) = parse_args(std::env::args().skip(1))?;
+    let (format, output_dir, input) = match args.as_slice() {
+        [format, output_dir] => (format, Path::new(output_dir), None),
+        [format, output_dir, input] => (format, Path::new(output_dir), Some(input.as_str()).filter(|input| *input != "-")),
+        _ => bail!(USAGE),
+    };
+    let format = match format.as_str() {
+        "csv" => Format::Csv,
+        "jsonl" => Format::Jsonl,
+        format => bail!("unknown format {:?}, expected csv or jsonl", format),
+    };
+    let reader: Box<dyn BufRead> = match input {
+        Some(input) => Box::new(BufReader::new(File::open(input).with_context(|| format!("opening {}", input))?)),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let pool = descriptor_pool()?;
+    let events_calls = pool.get_message_by_name(EVENTS_CALLS).context("missing EventsCalls descriptor")?;
+    let schemas: HashMap<String, Vec<Column>> = tables(&events_calls).into_iter()
+        .map(|(table, message)| (table, columns(&message)))
+        .collect();
+
+    fs::create_dir_all(output_dir)?;
+    let mut writers: HashMap<String, BufWriter<File>> = HashMap::new();
+    for output in read_outputs(reader, &pool)? {
+        let output = output?;
+        if output.message.descriptor().full_name() != EVENTS_CALLS || !range.contains(output.block_number) {
+            continue;
+        }
+
+        for row in rows(&output.message)? {
+            let columns = &schemas[&row.table];
+            let writer = match writers.entry(row.table.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let path = output_dir.join(format!("{}.{}", row.table, format.extension()));
+                    let mut writer = BufWriter::new(File::create(&path).with_context(|| format!("creating {}", path.display()))?);
+                    if format == Format::Csv {
+                        let header: Vec<&str> = columns.iter().map(|column| column.name.as_str()).collect();
+                        writeln!(writer, "{}", header.join(","))?;
+                    }
+                    entry.insert(writer)
+                }
+            };
+
+            write_row(writer, format, columns, &row)?;
+        }
+    }
+
+    for writer in writers.values_mut() {
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_field, BlockRange};
+
+    #[test]
+    fn csv_field_quoting() {
+        assert_eq!(csv_field("0xa0b8"), "0xa0b8");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_field("line\r\nbreak"), "\"line\r\nbreak\"");
+    }
+
+    #[test]
+    fn block_range_edges() {
+        let range = BlockRange { start: Some(100), stop: Some(200) };
+        assert!(!range.contains(99));
+        assert!(range.contains(100));
+        assert!(range.contains(199));
+        assert!(!range.contains(200));
+
+        assert!(BlockRange { start: Some(100), stop: None }.contains(u64::MAX));
+        assert!(BlockRange { start: None, stop: Some(100) }.contains(0));
+        assert!(!BlockRange { start: Some(100), stop: Some(100) }.contains(100));
+        assert!(BlockRange::default().contains(0));
+    }
+}
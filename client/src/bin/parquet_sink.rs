@@ -0,0 +1,236 @@
+//! Writes the `map_events_calls` outputs of a replay log, or of
+//! `substreams run -o jsonl` output, to Parquet files: one table per event and
+//! call type, partitioned by UTC day, under
+//! `<output_dir>/<table>/date=<YYYY-MM-DD>/part-<first_block>.parquet`.
+//!
+//! Addresses are stored as `FIXED_LEN_BYTE_ARRAY(20)`, null when empty, and
+//! `uint256` amounts as `DECIMAL(78, 0)`.
+//!
+//! Usage: `parquet_sink <output_dir> [<input>]`, reading stdin when no input
+//! file, or `-`, is given.
+
+use anyhow::{bail, Context, Result};
+use my_project_client::capture::{descriptor_pool, read_outputs};
+use my_project_client::dates::date_of;
+use my_project_client::tables::{columns, rows, tables, Cell, Column, ColumnKind, Row};
+use parquet::basic::{LogicalType, Repetition, TimeUnit, Type as PhysicalType};
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, FixedLenByteArray, FixedLenByteArrayType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::{SerializedColumnWriter, SerializedFileWriter};
+use parquet::format::MilliSeconds;
+use parquet::schema::types::Type;
+use prost_reflect::ReflectMessage;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::sync::Arc;
+
+const EVENTS_CALLS: &str = "contract.v1.EventsCalls";
+const SECONDS_PER_DAY: i64 = 86_400;
+const ADDRESS_LENGTH: i32 = 20;
+const BYTES32_LENGTH: i32 = 32;
+/// Enough for any `uint256` as a signed big-endian integer.
+const AMOUNT_LENGTH: i32 = 33;
+const AMOUNT_PRECISION: i32 = 78;
+
+fn field_type(column: &Column) -> Result<Type> {
+    let fixed = |length| Type::primitive_type_builder(&column.name, PhysicalType::FIXED_LEN_BYTE_ARRAY).with_length(length);
+
+    let builder = match column.kind {
+        ColumnKind::Text => Type::primitive_type_builder(&column.name, PhysicalType::BYTE_ARRAY)
+            .with_logical_type(Some(LogicalType::String)),
+        ColumnKind::Int => Type::primitive_type_builder(&column.name, PhysicalType::INT64)
+            .with_logical_type(Some(LogicalType::Integer { bit_width: 64, is_signed: false })),
+        ColumnKind::Bool => Type::primitive_type_builder(&column.name, PhysicalType::BOOLEAN),
+        ColumnKind::Timestamp => Type::primitive_type_builder(&column.name, PhysicalType::INT64)
+            .with_logical_type(Some(LogicalType::Timestamp {
+                is_adjusted_to_u_t_c: true,
+                unit: TimeUnit::MILLIS(MilliSeconds {}),
+            })),
+        ColumnKind::Address | ColumnKind::Addresses => fixed(ADDRESS_LENGTH),
+        ColumnKind::Bytes32 => fixed(BYTES32_LENGTH),
+        ColumnKind::Bytes => Type::primitive_type_builder(&column.name, PhysicalType::BYTE_ARRAY),
+        ColumnKind::Amount => fixed(AMOUNT_LENGTH)
+            .with_logical_type(Some(LogicalType::Decimal { scale: 0, precision: AMOUNT_PRECISION }))
+            .with_precision(AMOUNT_PRECISION)
+            .with_scale(0),
+    };
+    let repetition = match column.kind {
+        ColumnKind::Addresses => Repetition::REPEATED,
+        // Missing from outputs captured before the field was added.
+        ColumnKind::Address | ColumnKind::Bytes32 => Repetition::OPTIONAL,
+        _ => Repetition::REQUIRED,
+    };
+
+    Ok(builder.with_repetition(repetition).build()?)
+}
+
+fn schema(table: &str, columns: &[Column]) -> Result<Type> {
+    let fields = columns.iter().map(|column| field_type(column).map(Arc::new)).collect::<Result<Vec<_>>>()?;
+    Ok(Type::group_type_builder(table).with_fields(fields).build()?)
+}
+
+fn fixed_len(bytes: &[u8], length: i32, column: &Column) -> Result<FixedLenByteArray> {
+    if bytes.len() != length as usize {
+        bail!("column {} expects {} bytes, got {}", column.name, length, bytes.len());
+    }
+    Ok(FixedLenByteArray::from(ByteArray::from(bytes.to_vec())))
+}
+
+fn write_column(writer: &mut SerializedColumnWriter, column: &Column, cells: &[&Cell]) -> Result<()> {
+    let mismatch = |cell: &Cell| anyhow::anyhow!("unexpected {:?} in column {}", cell, column.name);
+
+    match column.kind {
+        ColumnKind::Text | ColumnKind::Bytes => {
+            let values = cells.iter()
+                .map(|cell| match cell {
+                    Cell::Text(text) => Ok(ByteArray::from(text.as_str())),
+                    Cell::Bytes(bytes) => Ok(ByteArray::from(bytes.clone())),
+                    cell => Err(mismatch(cell)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            writer.typed::<ByteArrayType>().write_batch(&values, None, None)?;
+        }
+        ColumnKind::Int | ColumnKind::Timestamp => {
+            let values = cells.iter()
+                .map(|cell| match cell {
+                    // Stored as the bits of the unsigned value.
+                    Cell::Int(value) => Ok(*value as i64),
+                    Cell::Timestamp(seconds) => Ok(seconds * 1_000),
+                    cell => Err(mismatch(cell)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            writer.typed::<Int64Type>().write_batch(&values, None, None)?;
+        }
+        ColumnKind::Bool => {
+            let values = cells.iter()
+                .map(|cell| match cell {
+                    Cell::Bool(value) => Ok(*value),
+                    cell => Err(mismatch(cell)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            writer.typed::<BoolType>().write_batch(&values, None, None)?;
+        }
+        ColumnKind::Amount => {
+            let values = cells.iter()
+                .map(|cell| match cell {
+                    Cell::Amount(amount) => {
+                        let mut bytes = [0u8; AMOUNT_LENGTH as usize];
+                        amount.to_big_endian(&mut bytes[1..]);
+                        fixed_len(&bytes, AMOUNT_LENGTH, column)
+                    }
+                    cell => Err(mismatch(cell)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            writer.typed::<FixedLenByteArrayType>().write_batch(&values, None, None)?;
+        }
+        ColumnKind::Address | ColumnKind::Bytes32 => {
+            let length = if column.kind == ColumnKind::Address { ADDRESS_LENGTH } else { BYTES32_LENGTH };
+            let (mut values, mut definition_levels) = (Vec::new(), Vec::new());
+            for cell in cells {
+                let (Cell::Address(bytes) | Cell::Bytes(bytes)) = cell else {
+                    return Err(mismatch(cell));
+                };
+
+                definition_levels.push(i16::from(!bytes.is_empty()));
+                if !bytes.is_empty() {
+                    values.push(fixed_len(bytes, length, column)?);
+                }
+            }
+            writer.typed::<FixedLenByteArrayType>().write_batch(&values, Some(&definition_levels), None)?;
+        }
+        ColumnKind::Addresses => {
+            let (mut values, mut definition_levels, mut repetition_levels) = (Vec::new(), Vec::new(), Vec::new());
+            for cell in cells {
+                let Cell::Addresses(addresses) = cell else {
+                    return Err(mismatch(cell));
+                };
+
+                if addresses.is_empty() {
+                    definition_levels.push(0);
+                    repetition_levels.push(0);
+                }
+                for (index, address) in addresses.iter().enumerate() {
+                    values.push(fixed_len(address, ADDRESS_LENGTH, column)?);
+                    definition_levels.push(1);
+                    repetition_levels.push(i16::from(index > 0));
+                }
+            }
+            writer.typed::<FixedLenByteArrayType>().write_batch(&values, Some(&definition_levels), Some(&repetition_levels))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_partition(output_dir: &Path, table: &str, day: i64, columns: &[Column], rows: &[Row]) -> Result<()> {
+    let directory = output_dir.join(table).join(format!("date={}", date_of(day)));
+    fs::create_dir_all(&directory)?;
+    let path = directory.join(format!("part-{}.parquet", rows[0].block_number));
+
+    let file = File::create(&path).with_context(|| format!("creating {}", path.display()))?;
+    let mut writer = SerializedFileWriter::new(file, Arc::new(schema(table, columns)?), Arc::new(WriterProperties::builder().build()))?;
+    let mut row_group = writer.next_row_group()?;
+    let mut index = 0;
+    while let Some(mut column_writer) = row_group.next_column()? {
+        let cells: Vec<&Cell> = rows.iter().map(|row| &row.cells[index]).collect();
+        write_column(&mut column_writer, &columns[index], &cells)?;
+        column_writer.close()?;
+        index += 1;
+    }
+    row_group.close()?;
+    writer.close()?;
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (output_dir, input) = match args.as_slice() {
+        [output_dir] => (Path::new(output_dir), None),
+        [output_dir, input] => (Path::new(output_dir), Some(input.as_str()).filter(|input| *input != "-")),
+        _ => bail!("usage: parquet_sink <output_dir> [<input>]"),
+    };
+    let reader: Box<dyn BufRead> = match input {
+        Some(input) => Box::new(BufReader::new(File::open(input).with_context(|| format!("opening {}", input))?)),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let pool = descriptor_pool()?;
+    let events_calls = pool.get_message_by_name(EVENTS_CALLS).context("missing EventsCalls descriptor")?;
+    let schemas: HashMap<String, Vec<Column>> = tables(&events_calls).into_iter()
+        .map(|(table, message)| (table, columns(&message)))
+        .collect();
+
+    // Rows are buffered per table and day, and written once a later day starts.
+    let mut partitions: BTreeMap<(String, i64), Vec<Row>> = BTreeMap::new();
+    let flush = |partitions: &mut BTreeMap<(String, i64), Vec<Row>>, before_day: i64| -> Result<()> {
+        let days: Vec<(String, i64)> = partitions.keys().filter(|(_, day)| *day < before_day).cloned().collect();
+        for key in days {
+            let rows = partitions.remove(&key).unwrap_or_default();
+            write_partition(output_dir, &key.0, key.1, &schemas[&key.0], &rows)?;
+        }
+        Ok(())
+    };
+
+    let mut current_day = i64::MIN;
+    for output in read_outputs(reader, &pool)? {
+        let output = output?;
+        if output.message.descriptor().full_name() != EVENTS_CALLS {
+            continue;
+        }
+
+        for row in rows(&output.message)? {
+            let day = row.timestamp.div_euclid(SECONDS_PER_DAY);
+            if day > current_day {
+                flush(&mut partitions, day)?;
+                current_day = day;
+            }
+            partitions.entry((row.table.clone(), day)).or_default().push(row);
+        }
+    }
+    flush(&mut partitions, i64::MAX)?;
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+//! Readers for captured module outputs, enabled by the `capture` feature.
+//!
+//! Two formats are supported, told apart by their first bytes:
+//! - the replay log written by `substreams gui` (`replay.log`), a `dbin` file
+//!   of `sf.substreams.rpc.v2` responses,
+//! - the JSON lines printed by `substreams run -o jsonl`.
+//!
+//! Outputs are decoded as `DynamicMessage`s, so both formats yield the same
+//! messages whatever the module.
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage};
+use std::io::{BufRead, Read};
+
+const DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/contract.bin"));
+const REPLAY_MAGIC: &[u8] = b"dbin";
+const BLOCK_SCOPED_DATA: &str = "type.googleapis.com/sf.substreams.rpc.v2.BlockScopedData";
+
+/// The output of a module for one block.
+pub struct ModuleOutput {
+    pub module: String,
+    pub block_number: u64,
+    pub message: DynamicMessage,
+}
+
+/// Subset of `sf.substreams.rpc.v2.BlockScopedData` holding the output module.
+#[derive(Clone, PartialEq, Message)]
+struct BlockScopedData {
+    #[prost(message, optional, tag = "1")]
+    output: Option<MapModuleOutput>,
+    #[prost(message, optional, tag = "2")]
+    clock: Option<Clock>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MapModuleOutput {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(message, optional, tag = "2")]
+    map_output: Option<prost_types::Any>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Clock {
+    #[prost(uint64, tag = "2")]
+    number: u64,
+}
+
+/// Descriptors of every message of the package, and of the well-known types
+/// they use.
+pub fn descriptor_pool() -> Result<DescriptorPool> {
+    Ok(DescriptorPool::decode(DESCRIPTOR_SET)?)
+}
+
+/// Reads every module output of a replay log or of `substreams run -o jsonl`
+/// output.
+pub fn read_outputs<'a>(
+    mut reader: impl BufRead + 'a,
+    pool: &'a DescriptorPool,
+) -> Result<Box<dyn Iterator<Item = Result<ModuleOutput>> + 'a>> {
+    if reader.fill_buf()?.starts_with(REPLAY_MAGIC) {
+        Ok(Box::new(replay_outputs(reader, pool)?))
+    } else {
+        Ok(Box::new(jsonl_outputs(reader, pool)))
+    }
+}
+
+fn replay_outputs<'a>(
+    mut reader: impl Read + 'a,
+    pool: &'a DescriptorPool,
+) -> Result<impl Iterator<Item = Result<ModuleOutput>> + 'a> {
+    // Header: magic, version, then the length-prefixed content type.
+    let mut header = [0u8; 7];
+    reader.read_exact(&mut header)?;
+    ensure!(header[4] == 1, "unsupported replay log version {}", header[4]);
+    let mut content_type = vec![0u8; u16::from_be_bytes([header[5], header[6]]) as usize];
+    reader.read_exact(&mut content_type)?;
+
+    Ok(std::iter::from_fn(move || {
+        loop {
+            let frame = match read_frame(&mut reader) {
+                Ok(Some(frame)) => frame,
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+
+            match decode_replay_frame(&frame, pool) {
+                Ok(Some(output)) => return Some(Ok(output)),
+                Ok(None) => continue,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }))
+}
+
+/// Reads the next message of a `dbin` file, prefixed by its big-endian length.
+fn read_frame(reader: &mut impl Read) -> Result<Option<Vec<u8>>> {
+    let mut length = [0u8; 4];
+    match reader.read_exact(&mut length) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err.into()),
+    }
+
+    let mut frame = vec![0u8; u32::from_be_bytes(length) as usize];
+    reader.read_exact(&mut frame).context("truncated replay log")?;
+    Ok(Some(frame))
+}
+
+/// Decodes the module output of a replayed response. Requests, session and
+/// progress messages are skipped.
+fn decode_replay_frame(frame: &[u8], pool: &DescriptorPool) -> Result<Option<ModuleOutput>> {
+    let response = prost_types::Any::decode(frame)?;
+    if response.type_url != BLOCK_SCOPED_DATA {
+        return Ok(None);
+    }
+
+    let data = BlockScopedData::decode(response.value.as_slice())?;
+    // Blocks in which the module skipped its output carry an empty `Any`.
+    let Some(MapModuleOutput { name, map_output: Some(output) }) = data.output else {
+        return Ok(None);
+    };
+    if output.type_url.is_empty() {
+        return Ok(None);
+    }
+
+    let type_name = output.type_url.rsplit('/').next().unwrap_or_default();
+    let descriptor = pool
+        .get_message_by_name(type_name)
+        .ok_or_else(|| anyhow!("unknown output type {:?} of module {}", type_name, name))?;
+
+    Ok(Some(ModuleOutput {
+        module: name,
+        block_number: data.clock.map(|clock| clock.number).unwrap_or_default(),
+        message: DynamicMessage::decode(descriptor, output.value.as_slice())?,
+    }))
+}
+
+fn jsonl_outputs<'a>(
+    reader: impl BufRead + 'a,
+    pool: &'a DescriptorPool,
+) -> impl Iterator<Item = Result<ModuleOutput>> + 'a {
+    reader.lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|(index, line)| decode_json_line(&line?, pool).with_context(|| format!("line {}", index + 1)))
+}
+
+/// Decodes a `{"@module", "@block", "@type", "@data"}` line.
+fn decode_json_line(line: &str, pool: &DescriptorPool) -> Result<ModuleOutput> {
+    let mut value: serde_json::Value = serde_json::from_str(line)?;
+
+    let module = value["@module"].as_str().unwrap_or_default().to_string();
+    let block_number = value["@block"].as_u64().ok_or_else(|| anyhow!("missing @block"))?;
+    let Some(type_name) = value["@type"].as_str() else {
+        bail!("missing @type");
+    };
+    let descriptor = pool
+        .get_message_by_name(type_name)
+        .ok_or_else(|| anyhow!("unknown output type {:?} of module {}", type_name, module))?;
+
+    Ok(ModuleOutput {
+        module,
+        block_number,
+        message: DynamicMessage::deserialize(descriptor, value["@data"].take())?,
+    })
+}
@@ -0,0 +1,25 @@
+//! Conversions of message fields to `ethabi` primitives. Addresses, nonces and
+//! signatures are raw bytes, amounts are decimal strings and transaction
+//! hashes are hex strings without the `0x` prefix.
+
+use anyhow::{anyhow, ensure, Result};
+use ethabi::{Address, Hash, Uint};
+use std::str::FromStr;
+
+pub fn address(bytes: &[u8]) -> Result<Address> {
+    ensure!(bytes.len() == 20, "address must be 20 bytes, got {}", bytes.len());
+    Ok(Address::from_slice(bytes))
+}
+
+pub fn bytes32(bytes: &[u8]) -> Result<Hash> {
+    ensure!(bytes.len() == 32, "bytes32 must be 32 bytes, got {}", bytes.len());
+    Ok(Hash::from_slice(bytes))
+}
+
+pub fn uint256(value: &str) -> Result<Uint> {
+    Uint::from_dec_str(value).map_err(|err| anyhow!("invalid uint256 {:?}: {:?}", value, err))
+}
+
+pub fn tx_hash(hash: &str) -> Result<Hash> {
+    Hash::from_str(hash.trim_start_matches("0x")).map_err(|err| anyhow!("invalid transaction hash {:?}: {}", hash, err))
+}
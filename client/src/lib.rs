@@ -0,0 +1,54 @@
+//! Native client for the outputs of the `my_project` Substreams modules.
+//!
+//! The message types are the ones the modules are built with, so the bytes of
+//! any module output, such as `map_events_calls` or `map_activity`, decode
+//! directly into them.
+
+pub mod convert;
+
+/// Date helpers shared with the Substreams modules.
+pub mod dates {
+    include!("../../src/dates.rs");
+}
+
+#[cfg(feature = "alloy")]
+pub mod alloy;
+#[cfg(feature = "capture")]
+pub mod capture;
+#[cfg(feature = "capture")]
+pub mod tables;
+
+pub mod pb {
+    pub mod contract {
+        pub mod v1 {
+            include!("../../src/pb/contract.v1.rs");
+        }
+    }
+}
+
+pub use pb::contract::v1 as contract;
+
+use contract::activity::Payload;
+use prost::Message;
+
+/// Decodes the output of `map_events_calls`.
+pub fn decode_events_calls(bytes: &[u8]) -> Result<contract::EventsCalls, prost::DecodeError> {
+    contract::EventsCalls::decode(bytes)
+}
+
+/// Decodes the output of `map_activity`.
+pub fn decode_activities(bytes: &[u8]) -> Result<contract::Activities, prost::DecodeError> {
+    contract::Activities::decode(bytes)
+}
+
+/// Iterates the activities of a `map_activity` output in execution order,
+/// yielding each one with its decoded event or call.
+pub fn activities(bytes: &[u8]) -> Result<impl Iterator<Item = (contract::Activity, Payload)>, prost::DecodeError> {
+    let mut activities = decode_activities(bytes)?.activities;
+    activities.sort_by_key(|activity| activity.ordinal);
+
+    Ok(activities.into_iter().filter_map(|mut activity| {
+        let payload = activity.payload.take()?;
+        Some((activity, payload))
+    }))
+}
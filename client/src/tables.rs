@@ -0,0 +1,216 @@
+//! Flattens `EventsCalls` outputs into one table per event and call type,
+//! enabled by the `capture` feature.
+//!
+//! Tables are named after the `Events` and `Calls` fields, without the
+//! `usdc_` prefix and the plural, such as `transfer` or `call_permit_1`, and
+//! have one column per message field.
+
+use crate::dates::date_of;
+use anyhow::{anyhow, ensure, Result};
+use ethabi::Uint;
+use prost_reflect::{DynamicMessage, FieldDescriptor, Kind, MessageDescriptor, ReflectMessage, Value};
+
+/// Non-numeric strings. Every other string field holds a `uint256`.
+const TEXT_FIELDS: [&str; 5] = ["token_name", "token_symbol", "token_currency", "new_name", "new_symbol"];
+/// 32-byte values. Other than these, signatures and calldata, bytes hold
+/// addresses.
+const BYTES32_FIELDS: [&str; 3] = ["nonce", "r", "s"];
+const BYTES_FIELDS: [&str; 2] = ["signature", "data"];
+const SECONDS_PER_DAY: i64 = 86_400;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnKind {
+    Text,
+    Int,
+    Bool,
+    /// Seconds since the Unix epoch.
+    Timestamp,
+    Address,
+    Addresses,
+    Bytes32,
+    Bytes,
+    /// `uint256`, as a decimal string.
+    Amount,
+}
+
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub name: String,
+    pub kind: ColumnKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum Cell {
+    Text(String),
+    Int(u64),
+    Bool(bool),
+    Timestamp(i64),
+    Address(Vec<u8>),
+    Addresses(Vec<Vec<u8>>),
+    Bytes(Vec<u8>),
+    Amount(Uint),
+}
+
+/// One decoded event or call.
+pub struct Row {
+    pub table: String,
+    pub block_number: u64,
+    /// Block timestamp, in seconds since the Unix epoch.
+    pub timestamp: i64,
+    pub cells: Vec<Cell>,
+}
+
+/// Formats seconds since the Unix epoch as an RFC 3339 UTC timestamp.
+pub fn format_timestamp(seconds: i64) -> String {
+    let time = seconds.rem_euclid(SECONDS_PER_DAY);
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        date_of(seconds.div_euclid(SECONDS_PER_DAY)),
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60,
+    )
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let digits: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{}", digits)
+}
+
+impl Cell {
+    /// Text representation, with `0x` hex bytes and decimal amounts. Lists of
+    /// addresses are joined with `|`, and empty bytes are left empty.
+    pub fn to_text(&self) -> String {
+        match self {
+            Cell::Address(bytes) | Cell::Bytes(bytes) if bytes.is_empty() => String::new(),
+            Cell::Text(text) => text.clone(),
+            Cell::Int(value) => value.to_string(),
+            Cell::Bool(value) => value.to_string(),
+            Cell::Timestamp(seconds) => format_timestamp(*seconds),
+            Cell::Address(bytes) | Cell::Bytes(bytes) => hex(bytes),
+            Cell::Addresses(addresses) => addresses.iter().map(|address| hex(address)).collect::<Vec<_>>().join("|"),
+            Cell::Amount(amount) => amount.to_string(),
+        }
+    }
+
+    /// JSON representation. Amounts are decimal strings, since they overflow
+    /// JSON numbers, and empty bytes are null.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Cell::Address(bytes) | Cell::Bytes(bytes) if bytes.is_empty() => serde_json::Value::Null,
+            Cell::Int(value) => (*value).into(),
+            Cell::Bool(value) => (*value).into(),
+            Cell::Addresses(addresses) => addresses.iter().map(|address| hex(address)).collect(),
+            cell => cell.to_text().into(),
+        }
+    }
+}
+
+fn table_name(field: &FieldDescriptor) -> String {
+    let name = field.name();
+    let name = name.strip_prefix("usdc_").unwrap_or(name);
+    name.strip_suffix('s').unwrap_or(name).to_string()
+}
+
+fn column_kind(field: &FieldDescriptor) -> ColumnKind {
+    let name = field.name();
+    match field.kind() {
+        Kind::Message(message) if message.full_name() == "google.protobuf.Timestamp" => ColumnKind::Timestamp,
+        Kind::Bool => ColumnKind::Bool,
+        Kind::Uint32 | Kind::Uint64 => ColumnKind::Int,
+        Kind::String if name.ends_with("_hash") || TEXT_FIELDS.contains(&name) => ColumnKind::Text,
+        Kind::String => ColumnKind::Amount,
+        Kind::Bytes if field.is_list() => ColumnKind::Addresses,
+        Kind::Bytes if BYTES32_FIELDS.contains(&name) => ColumnKind::Bytes32,
+        Kind::Bytes if BYTES_FIELDS.contains(&name) => ColumnKind::Bytes,
+        Kind::Bytes => ColumnKind::Address,
+        _ => ColumnKind::Text,
+    }
+}
+
+/// Columns of the table of an event or call message.
+pub fn columns(message: &MessageDescriptor) -> Vec<Column> {
+    message.fields()
+        .map(|field| Column { name: field.name().to_string(), kind: column_kind(&field) })
+        .collect()
+}
+
+/// Every table of an `EventsCalls` output, with the descriptor of its rows.
+pub fn tables(events_calls: &MessageDescriptor) -> Vec<(String, MessageDescriptor)> {
+    events_calls.fields()
+        .filter_map(|group| match group.kind() {
+            Kind::Message(group) => Some(group),
+            _ => None,
+        })
+        .flat_map(|group| group.fields().collect::<Vec<_>>())
+        .filter_map(|field| match field.kind() {
+            Kind::Message(message) if field.is_list() => Some((table_name(&field), message)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn cell(kind: ColumnKind, value: &Value) -> Result<Cell> {
+    let invalid = || anyhow!("unexpected {:?} value for a {:?} column", value, kind);
+    let bytes = |value: &Value| value.as_bytes().map(|bytes| bytes.to_vec()).ok_or_else(invalid);
+
+    Ok(match kind {
+        ColumnKind::Text => Cell::Text(value.as_str().ok_or_else(invalid)?.to_string()),
+        ColumnKind::Int => Cell::Int(value.as_u64().or(value.as_u32().map(u64::from)).ok_or_else(invalid)?),
+        ColumnKind::Bool => Cell::Bool(value.as_bool().ok_or_else(invalid)?),
+        ColumnKind::Timestamp => {
+            let seconds = value.as_message().and_then(|time| time.get_field_by_name("seconds"));
+            Cell::Timestamp(seconds.and_then(|seconds| seconds.as_i64()).unwrap_or_default())
+        }
+        ColumnKind::Address => Cell::Address(bytes(value)?),
+        ColumnKind::Bytes32 | ColumnKind::Bytes => Cell::Bytes(bytes(value)?),
+        ColumnKind::Addresses => Cell::Addresses(value.as_list().ok_or_else(invalid)?.iter().map(bytes).collect::<Result<_>>()?),
+        ColumnKind::Amount => {
+            let amount = value.as_str().ok_or_else(invalid)?;
+            Cell::Amount(Uint::from_dec_str(amount).map_err(|err| anyhow!("invalid uint256 {:?}: {:?}", amount, err))?)
+        }
+    })
+}
+
+/// Flattens an `EventsCalls` output into rows, table by table.
+pub fn rows(events_calls: &DynamicMessage) -> Result<Vec<Row>> {
+    let descriptor = events_calls.descriptor();
+    ensure!(descriptor.full_name() == "contract.v1.EventsCalls", "expected EventsCalls, got {}", descriptor.full_name());
+
+    let mut rows = Vec::new();
+    for group in descriptor.fields() {
+        let group_value = events_calls.get_field(&group);
+        let Some(group_message) = group_value.as_message() else {
+            continue;
+        };
+
+        for field in group_message.descriptor().fields().filter(|field| field.is_list()) {
+            let Kind::Message(message) = field.kind() else {
+                continue;
+            };
+            let fields: Vec<FieldDescriptor> = message.fields().collect();
+            let columns = columns(&message);
+
+            for item in group_message.get_field(&field).as_list().unwrap_or_default() {
+                let Some(item) = item.as_message() else {
+                    continue;
+                };
+
+                let cells = fields.iter().zip(&columns)
+                    .map(|(field, column)| cell(column.kind, &item.get_field(field)))
+                    .collect::<Result<Vec<_>>>()?;
+                let block_number = cells.iter().zip(&columns)
+                    .find(|(_, column)| column.name.ends_with("_block_number"))
+                    .and_then(|(cell, _)| match cell { Cell::Int(number) => Some(*number), _ => None })
+                    .unwrap_or_default();
+                let timestamp = cells.iter()
+                    .find_map(|cell| match cell { Cell::Timestamp(seconds) => Some(*seconds), _ => None })
+                    .unwrap_or_default();
+
+                rows.push(Row { table: table_name(&field), block_number, timestamp, cells });
+            }
+        }
+    }
+
+    Ok(rows)
+}
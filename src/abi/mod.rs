@@ -1,2 +1,2 @@
-
+#[allow(clippy::all)]
 pub mod usdc_contract;
@@ -0,0 +1,57 @@
+use crate::calls::for_each_call_field;
+use crate::events::for_each_event_field;
+use crate::pb::contract::v1 as contract;
+use contract::activity::Payload;
+
+macro_rules! event_activities {
+    ($events:expr, $activities:expr, $($field:ident: $variant:ident => $event:literal,)*) => {
+        $(
+            $activities.extend($events.$field.into_iter().map(|event| contract::Activity {
+                id: format!("{}-{}", event.evt_tx_hash, event.evt_index),
+                ordinal: event.evt_ordinal,
+                tx_hash: event.evt_tx_hash.clone(),
+                block_number: event.evt_block_number,
+                block_time: event.evt_block_time.clone(),
+                payload: Some(Payload::$variant(event)),
+            }));
+        )*
+    };
+}
+
+macro_rules! call_activities {
+    ($calls:expr, $activities:expr, $($field:ident: $variant:ident => $function:literal,)*) => {
+        $(
+            $activities.extend($calls.$field.into_iter().map(|call| contract::Activity {
+                id: format!("{}-call-{}", call.call_tx_hash, call.call_ordinal),
+                ordinal: call.call_ordinal,
+                tx_hash: call.call_tx_hash.clone(),
+                block_number: call.call_block_number,
+                block_time: call.call_block_time.clone(),
+                payload: Some(Payload::$variant(call)),
+            }));
+        )*
+    };
+}
+
+/// Flattens the events and calls of a block into a single list, in execution
+/// order. Events are identified by `<tx_hash>-<log_index>` and calls by
+/// `<tx_hash>-call-<ordinal>`.
+pub fn activities(events: contract::Events, calls: contract::Calls) -> Vec<contract::Activity> {
+    let mut activities = Vec::new();
+    for_each_event_field!(event_activities!(events, activities,));
+    for_each_call_field!(call_activities!(calls, activities,));
+
+    activities.sort_by_key(|activity| activity.ordinal);
+    activities
+}
+
+#[substreams::handlers::map]
+fn map_activity(
+    events: contract::Events,
+    calls: contract::Calls,
+) -> Result<contract::Activities, substreams::errors::Error> {
+    let activities = activities(events, calls);
+
+    substreams::skip_empty_output();
+    Ok(contract::Activities { activities })
+}
@@ -0,0 +1,121 @@
+use crate::calls::for_each_call_field;
+use crate::pb::contract::v1 as contract;
+use crate::ZERO_ADDRESS;
+use substreams::pb::substreams::Clock;
+use substreams::store::{
+    StoreAdd, StoreAddInt64, StoreGet, StoreGetInt64, StoreMax, StoreMaxInt64, StoreNew, StoreSet,
+    StoreSetIfNotExists, StoreSetIfNotExistsInt64, StoreSetInt64,
+};
+use substreams::Hex;
+use std::collections::BTreeSet;
+
+macro_rules! collect_callers {
+    ($calls:expr, $addresses:expr, $($field:ident: $variant:ident => $function:literal,)*) => {
+        $(
+            $addresses.extend($calls.$field.iter().map(|call| call.call_caller.clone()));
+        )*
+    };
+}
+
+pub fn address_key(address: &[u8]) -> String {
+    format!("address:{}", Hex(address))
+}
+
+pub fn blacklist_key(address: &[u8]) -> String {
+    format!("blacklisted:{}", Hex(address))
+}
+
+/// Every address that sent or received a transfer, or called the contract, in
+/// the block.
+pub fn touched_addresses(events: &contract::Events, calls: &contract::Calls) -> BTreeSet<Vec<u8>> {
+    let mut addresses = BTreeSet::new();
+    for transfer in &events.usdc_transfers {
+        addresses.insert(transfer.from.clone());
+        addresses.insert(transfer.to.clone());
+    }
+    for_each_call_field!(collect_callers!(calls, addresses,));
+
+    addresses.remove(ZERO_ADDRESS.as_slice());
+    addresses
+}
+
+#[substreams::handlers::store]
+fn store_address_first_seen(clock: Clock, events: contract::Events, calls: contract::Calls, store: StoreSetIfNotExistsInt64) {
+    for address in touched_addresses(&events, &calls) {
+        store.set_if_not_exists(0, address_key(&address), &(clock.number as i64));
+    }
+}
+
+#[substreams::handlers::store]
+fn store_address_last_seen(clock: Clock, events: contract::Events, calls: contract::Calls, store: StoreMaxInt64) {
+    for address in touched_addresses(&events, &calls) {
+        store.max(0, address_key(&address), clock.number as i64);
+    }
+}
+
+/// Counts the transfers sent (`sent:` keys) and received (`received:` keys)
+/// by every address.
+#[substreams::handlers::store]
+fn store_address_transfers(events: contract::Events, store: StoreAddInt64) {
+    for transfer in events.usdc_transfers {
+        let ordinal = transfer.evt_ordinal;
+        if transfer.from != ZERO_ADDRESS {
+            store.add(ordinal, format!("sent:{}", Hex(&transfer.from)), 1);
+        }
+        if transfer.to != ZERO_ADDRESS {
+            store.add(ordinal, format!("received:{}", Hex(&transfer.to)), 1);
+        }
+    }
+}
+
+/// Every address blacklisted or un-blacklisted in the block.
+pub fn blacklist_changes(events: &contract::Events) -> BTreeSet<Vec<u8>> {
+    let mut addresses = BTreeSet::new();
+    addresses.extend(events.usdc_blacklisteds.iter().map(|event| event.u_account.clone()));
+    addresses.extend(events.usdc_un_blacklisteds.iter().map(|event| event.u_account.clone()));
+
+    addresses
+}
+
+/// Keeps the blacklist status of every address, `1` while blacklisted and
+/// `0` once un-blacklisted.
+#[substreams::handlers::store]
+fn store_blacklist(events: contract::Events, store: StoreSetInt64) {
+    let mut changes: Vec<(u64, &Vec<u8>, i64)> = Vec::new();
+    changes.extend(events.usdc_blacklisteds.iter().map(|event| (event.evt_ordinal, &event.u_account, 1)));
+    changes.extend(events.usdc_un_blacklisteds.iter().map(|event| (event.evt_ordinal, &event.u_account, 0)));
+
+    changes.sort_by_key(|(ordinal, _, _)| *ordinal);
+    for (ordinal, address, blacklisted) in changes {
+        store.set(ordinal, blacklist_key(address), &blacklisted);
+    }
+}
+
+/// Emits the activity index entry of every address touched in the block.
+#[substreams::handlers::map]
+fn map_address_activity(
+    clock: Clock,
+    events: contract::Events,
+    calls: contract::Calls,
+    first_seen: StoreGetInt64,
+    last_seen: StoreGetInt64,
+    transfers: StoreGetInt64,
+) -> Result<contract::AddressActivities, substreams::errors::Error> {
+    let address_activities = touched_addresses(&events, &calls).into_iter()
+        .map(|address| {
+            let first_seen_block = first_seen.get_last(address_key(&address)).unwrap_or(clock.number as i64) as u64;
+
+            contract::AddressActivity {
+                first_seen_block,
+                last_seen_block: last_seen.get_last(address_key(&address)).unwrap_or(clock.number as i64) as u64,
+                transfers_sent: transfers.get_last(format!("sent:{}", Hex(&address))).unwrap_or_default() as u64,
+                transfers_received: transfers.get_last(format!("received:{}", Hex(&address))).unwrap_or_default() as u64,
+                new_address: first_seen_block == clock.number,
+                address,
+            }
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::AddressActivities { address_activities })
+}
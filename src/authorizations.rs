@@ -0,0 +1,142 @@
+use crate::pb::contract::v1 as contract;
+use substreams::store::{StoreGet, StoreGetProto, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsProto};
+use substreams::Hex;
+
+/// A `transferWithAuthorization`, `receiveWithAuthorization` or
+/// `cancelAuthorization` call, whichever overload was used.
+struct AuthorizationCall<'a> {
+    function: &'static str,
+    tx_hash: &'a str,
+    block_time: &'a Option<prost_types::Timestamp>,
+    block_number: u64,
+    begin_ordinal: u64,
+    end_ordinal: u64,
+    success: bool,
+    authorizer: &'a [u8],
+    nonce: &'a [u8],
+    submitter: &'a [u8],
+    payee: &'a [u8],
+    value: &'a str,
+}
+
+macro_rules! authorization_calls {
+    ($calls:expr, $authorization_calls:expr, $($field:ident => $function:literal { $authorizer:ident $(, $payee:ident, $value:ident)? },)*) => {
+        $(
+            $authorization_calls.extend($calls.$field.iter()
+                .map(|call| AuthorizationCall {
+                    function: $function,
+                    tx_hash: &call.call_tx_hash,
+                    block_time: &call.call_block_time,
+                    block_number: call.call_block_number,
+                    begin_ordinal: call.call_ordinal,
+                    end_ordinal: call.call_end_ordinal,
+                    success: call.call_success,
+                    authorizer: &call.$authorizer,
+                    nonce: &call.nonce,
+                    submitter: &call.call_caller,
+                    payee: [$(call.$payee.as_slice())?].first().copied().unwrap_or_default(),
+                    value: [$(call.$value.as_str())?].first().copied().unwrap_or_default(),
+                }));
+        )*
+    };
+}
+
+fn authorization_calls(calls: &contract::Calls) -> Vec<AuthorizationCall<'_>> {
+    let mut authorization_calls = Vec::new();
+    authorization_calls!(calls, authorization_calls,
+        usdc_call_transfer_with_authorization_1s => "transferWithAuthorization" { from, to, value },
+        usdc_call_transfer_with_authorization_2s => "transferWithAuthorization" { from, to, value },
+        usdc_call_receive_with_authorization_1s => "receiveWithAuthorization" { from, to, value },
+        usdc_call_receive_with_authorization_2s => "receiveWithAuthorization" { from, to, value },
+        usdc_call_cancel_authorization_1s => "cancelAuthorization" { authorizer },
+        usdc_call_cancel_authorization_2s => "cancelAuthorization" { authorizer },
+    );
+
+    authorization_calls
+}
+
+pub fn authorization_key(authorizer: &[u8], nonce: &[u8]) -> String {
+    format!("authorization:{}:{}", Hex(authorizer), Hex(nonce))
+}
+
+/// Records the use of every authorization nonce, from `AuthorizationUsed`
+/// events, with the function and submitter of the call that emitted it.
+#[substreams::handlers::store]
+fn store_authorizations(events: contract::Events, calls: contract::Calls, store: StoreSetIfNotExistsProto<contract::AuthorizationUse>) {
+    let authorization_calls = authorization_calls(&calls);
+
+    for used in events.usdc_authorization_useds {
+        let call = authorization_calls.iter().find(|call| {
+            call.success
+                && call.tx_hash == used.evt_tx_hash
+                && call.begin_ordinal <= used.evt_ordinal
+                && used.evt_ordinal <= call.end_ordinal
+                && call.authorizer == used.authorizer.as_slice()
+                && call.nonce == used.nonce.as_slice()
+        });
+
+        store.set_if_not_exists(used.evt_ordinal, authorization_key(&used.authorizer, &used.nonce), &contract::AuthorizationUse {
+            tx_hash: used.evt_tx_hash,
+            block_time: used.evt_block_time,
+            block_number: used.evt_block_number,
+            ordinal: used.evt_ordinal,
+            submitter: call.map(|call| call.submitter.to_vec()).unwrap_or_default(),
+            function: call.map(|call| call.function.to_string()).unwrap_or_default(),
+            authorizer: used.authorizer,
+            nonce: used.nonce,
+        });
+    }
+}
+
+/// Flags `transferWithAuthorization` and `receiveWithAuthorization` calls
+/// that conflict with another use of the same authorization: reverted calls
+/// for a nonce used in another transaction, a replay when the submitter is
+/// the same and a front-run when it differs, and `receiveWithAuthorization`
+/// calls not sent by the payee. Reverted calls include the top-level calls
+/// of failed transactions. Uses later in the same block are matched too, but
+/// reverted calls from blocks before the use are not.
+#[substreams::handlers::map]
+fn map_authorization_conflicts(
+    calls: contract::Calls,
+    authorizations: StoreGetProto<contract::AuthorizationUse>,
+) -> Result<contract::AuthorizationConflicts, substreams::errors::Error> {
+    let authorization_conflicts = authorization_calls(&calls).into_iter()
+        .filter(|call| call.function != "cancelAuthorization")
+        .filter_map(|call| {
+            let used = authorizations.get_last(authorization_key(call.authorizer, call.nonce));
+            let kind = if call.function == "receiveWithAuthorization" && call.submitter != call.payee {
+                contract::ConflictKind::NonPayeeReceive
+            } else {
+                let used = used.as_ref().filter(|used| !call.success && used.tx_hash != call.tx_hash)?;
+                if used.submitter == call.submitter {
+                    contract::ConflictKind::Replay
+                } else {
+                    contract::ConflictKind::FrontRun
+                }
+            };
+            let used = used.unwrap_or_default();
+
+            Some(contract::AuthorizationConflict {
+                kind: kind as i32,
+                function: call.function.to_string(),
+                tx_hash: call.tx_hash.to_string(),
+                block_time: call.block_time.clone(),
+                block_number: call.block_number,
+                ordinal: call.begin_ordinal,
+                success: call.success,
+                authorizer: call.authorizer.to_vec(),
+                nonce: call.nonce.to_vec(),
+                submitter: call.submitter.to_vec(),
+                payee: call.payee.to_vec(),
+                value: call.value.to_string(),
+                used_tx_hash: used.tx_hash,
+                used_block_number: used.block_number,
+                used_submitter: used.submitter,
+                used_function: used.function,
+            })
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::AuthorizationConflicts { authorization_conflicts })
+}
@@ -0,0 +1,153 @@
+use crate::abi::usdc_contract::functions::BalanceOf;
+use crate::addresses::touched_addresses;
+use crate::params::Params;
+use crate::pb::contract::v1 as contract;
+use crate::{USDC_TRACKED_CONTRACT, ZERO_ADDRESS};
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    StoreAdd, StoreAddBigInt, StoreGet, StoreGetBigInt, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsBigInt,
+};
+use substreams::Hex;
+use substreams_ethereum::rpc::RpcBatch;
+use std::str::FromStr;
+
+struct Sampling {
+    sample_rate: u64,
+    max_checks: usize,
+}
+
+impl Sampling {
+    fn from_params(params: &str) -> Result<Self, substreams::errors::Error> {
+        let params = Params::parse(params)?;
+
+        Ok(Sampling {
+            sample_rate: params.parse_or::<u64>("sample_rate", 100)?.max(1),
+            max_checks: params.parse_or("max_checks", 20)?,
+        })
+    }
+
+    /// Samples a fixed subset of addresses, so that each one is checked every
+    /// time it is touched.
+    fn includes(&self, address: &[u8]) -> bool {
+        let suffix = address.iter().fold(0u64, |suffix, byte| suffix << 8 | u64::from(*byte));
+        suffix % self.sample_rate == 0
+    }
+}
+
+pub fn balance_key(address: &[u8]) -> String {
+    format!("balance:{}", Hex(address))
+}
+
+fn baseline_key(address: &[u8]) -> String {
+    format!("baseline:{}", Hex(address))
+}
+
+/// Net balance change of every address since the module's initial block,
+/// derived from `Transfer` events. Mints and burns move funds from and to the
+/// zero address, which is not tracked.
+#[substreams::handlers::store]
+fn store_balances(events: contract::Events, store: StoreAddBigInt) {
+    for transfer in events.usdc_transfers {
+        let value = BigInt::from_str(&transfer.value).unwrap_or_default();
+        let ordinal = transfer.evt_ordinal;
+
+        if transfer.from != ZERO_ADDRESS {
+            store.add(ordinal, balance_key(&transfer.from), value.neg());
+        }
+        if transfer.to != ZERO_ADDRESS {
+            store.add(ordinal, balance_key(&transfer.to), value);
+        }
+    }
+}
+
+/// Reads the on-chain `balanceOf` of the sampled addresses touched in the
+/// block, along with the net change tracked by `store_balances`.
+#[substreams::handlers::map]
+fn map_balance_observations(
+    params: String,
+    clock: Clock,
+    events: contract::Events,
+    calls: contract::Calls,
+    balances: StoreGetBigInt,
+) -> Result<contract::BalanceObservations, substreams::errors::Error> {
+    let sampling = Sampling::from_params(&params)?;
+    let addresses: Vec<Vec<u8>> = touched_addresses(&events, &calls).into_iter()
+        .filter(|address| sampling.includes(address))
+        .take(sampling.max_checks)
+        .collect();
+    if addresses.is_empty() {
+        substreams::skip_empty_output();
+        return Ok(contract::BalanceObservations::default());
+    }
+
+    let responses = addresses.iter()
+        .fold(RpcBatch::new(), |batch, address| {
+            batch.add(BalanceOf { account: address.clone() }, USDC_TRACKED_CONTRACT.to_vec())
+        })
+        .execute()
+        .map_err(substreams::errors::Error::msg)?
+        .responses;
+
+    let balance_observations = addresses.into_iter()
+        .zip(responses)
+        .filter_map(|(address, response)| {
+            let observed = RpcBatch::decode::<_, BalanceOf>(&response)?;
+
+            Some(contract::BalanceObservation {
+                block_number: clock.number,
+                block_time: clock.timestamp.clone(),
+                observed_balance: observed.to_string(),
+                tracked_change: balances.get_last(balance_key(&address)).unwrap_or_default().to_string(),
+                address,
+            })
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::BalanceObservations { balance_observations })
+}
+
+/// Records, on the first observation of each address, its balance before the
+/// initial block: the observed balance minus the tracked change.
+#[substreams::handlers::store]
+fn store_balance_baselines(observations: contract::BalanceObservations, store: StoreSetIfNotExistsBigInt) {
+    for observation in observations.balance_observations {
+        let observed = BigInt::from_str(&observation.observed_balance).unwrap_or_default();
+        let tracked = BigInt::from_str(&observation.tracked_change).unwrap_or_default();
+
+        store.set_if_not_exists(0, baseline_key(&observation.address), &(observed - tracked));
+    }
+}
+
+/// Flags the observations whose on-chain balance differs from the baseline
+/// plus the tracked change, meaning the balance moved without a matching
+/// `Transfer` event being decoded.
+#[substreams::handlers::map]
+fn map_balance_mismatches(
+    observations: contract::BalanceObservations,
+    baselines: StoreGetBigInt,
+) -> Result<contract::BalanceMismatches, substreams::errors::Error> {
+    let balance_mismatches = observations.balance_observations.into_iter()
+        .filter_map(|observation| {
+            let baseline = baselines.get_last(baseline_key(&observation.address))?;
+            let observed = BigInt::from_str(&observation.observed_balance).unwrap_or_default();
+            let expected = baseline + BigInt::from_str(&observation.tracked_change).unwrap_or_default();
+            if observed == expected {
+                return None;
+            }
+
+            Some(contract::BalanceMismatch {
+                address: observation.address,
+                block_number: observation.block_number,
+                block_time: observation.block_time,
+                difference: (observed.clone() - expected.clone()).to_string(),
+                observed_balance: observed.to_string(),
+                expected_balance: expected.to_string(),
+            })
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::BalanceMismatches { balance_mismatches })
+}
@@ -0,0 +1,82 @@
+use crate::abi::usdc_contract::functions;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Function;
+
+/// Invokes `$callback!` with every repeated field of `contract::Calls`, each
+/// paired with its `activity::Payload` variant and the name of the contract
+/// function it decodes. Overloaded functions appear once per overload, under
+/// the same name.
+macro_rules! for_each_call_field {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback!($($args)*
+            usdc_call_approves: UsdcCallApprove => "approve",
+            usdc_call_blacklists: UsdcCallBlacklist => "blacklist",
+            usdc_call_burns: UsdcCallBurn => "burn",
+            usdc_call_cancel_authorization_1s: UsdcCallCancelAuthorization1 => "cancelAuthorization",
+            usdc_call_cancel_authorization_2s: UsdcCallCancelAuthorization2 => "cancelAuthorization",
+            usdc_call_change_admins: UsdcCallChangeAdmin => "changeAdmin",
+            usdc_call_configure_minters: UsdcCallConfigureMinter => "configureMinter",
+            usdc_call_decrease_allowances: UsdcCallDecreaseAllowance => "decreaseAllowance",
+            usdc_call_increase_allowances: UsdcCallIncreaseAllowance => "increaseAllowance",
+            usdc_call_initializes: UsdcCallInitialize => "initialize",
+            usdc_call_initialize_v_2s: UsdcCallInitializeV2 => "initializeV2",
+            usdc_call_initialize_v2_1s: UsdcCallInitializeV21 => "initializeV2_1",
+            usdc_call_initialize_v2_2s: UsdcCallInitializeV22 => "initializeV2_2",
+            usdc_call_mints: UsdcCallMint => "mint",
+            usdc_call_pauses: UsdcCallPause => "pause",
+            usdc_call_permit_1s: UsdcCallPermit1 => "permit",
+            usdc_call_permit_2s: UsdcCallPermit2 => "permit",
+            usdc_call_receive_with_authorization_1s: UsdcCallReceiveWithAuthorization1 => "receiveWithAuthorization",
+            usdc_call_receive_with_authorization_2s: UsdcCallReceiveWithAuthorization2 => "receiveWithAuthorization",
+            usdc_call_remove_minters: UsdcCallRemoveMinter => "removeMinter",
+            usdc_call_rescue_erc_20s: UsdcCallRescueErc20 => "rescueERC20",
+            usdc_call_transfers: UsdcCallTransfer => "transfer",
+            usdc_call_transfer_froms: UsdcCallTransferFrom => "transferFrom",
+            usdc_call_transfer_ownerships: UsdcCallTransferOwnership => "transferOwnership",
+            usdc_call_transfer_with_authorization_1s: UsdcCallTransferWithAuthorization1 => "transferWithAuthorization",
+            usdc_call_transfer_with_authorization_2s: UsdcCallTransferWithAuthorization2 => "transferWithAuthorization",
+            usdc_call_un_blacklists: UsdcCallUnBlacklist => "unBlacklist",
+            usdc_call_unpauses: UsdcCallUnpause => "unpause",
+            usdc_call_update_blacklisters: UsdcCallUpdateBlacklister => "updateBlacklister",
+            usdc_call_update_master_minters: UsdcCallUpdateMasterMinter => "updateMasterMinter",
+            usdc_call_update_pausers: UsdcCallUpdatePauser => "updatePauser",
+            usdc_call_update_rescuers: UsdcCallUpdateRescuer => "updateRescuer",
+            usdc_call_upgrade_tos: UsdcCallUpgradeTo => "upgradeTo",
+            usdc_call_upgrade_to_and_calls: UsdcCallUpgradeToAndCall => "upgradeToAndCall",
+        )
+    };
+}
+pub(crate) use for_each_call_field;
+
+macro_rules! function_names {
+    ($($field:ident: $variant:ident => $function:literal,)*) => {
+        [$($function),*]
+    };
+}
+
+/// Names of the contract functions decoded into `contract::Calls`.
+pub const FUNCTION_NAMES: [&str; 34] = for_each_call_field!(function_names!());
+
+macro_rules! function_name {
+    ($call:expr, $($function:ident,)*) => {
+        $(
+            if functions::$function::match_call($call) {
+                return Some(<functions::$function as Function>::NAME);
+            }
+        )*
+    };
+}
+
+/// Name of the contract function called, if it is one of `FUNCTION_NAMES`.
+pub(crate) fn function_name(call: &eth::Call) -> Option<&'static str> {
+    function_name!(call,
+        Approve, Blacklist, Burn, CancelAuthorization1, CancelAuthorization2, ChangeAdmin, ConfigureMinter,
+        DecreaseAllowance, IncreaseAllowance, Initialize, InitializeV2, InitializeV21, InitializeV22, Mint,
+        Pause, Permit1, Permit2, ReceiveWithAuthorization1, ReceiveWithAuthorization2, RemoveMinter,
+        RescueErc20, Transfer, TransferFrom, TransferOwnership, TransferWithAuthorization1,
+        TransferWithAuthorization2, UnBlacklist, Unpause, UpdateBlacklister, UpdateMasterMinter,
+        UpdatePauser, UpdateRescuer, UpgradeTo, UpgradeToAndCall,
+    );
+
+    None
+}
@@ -0,0 +1,48 @@
+// Shared with the client crate through `include!`, so it depends on `std`
+// alone.
+
+/// Formats a count of days since the Unix epoch as a `YYYY-MM-DD` date.
+pub fn date_of(day: i64) -> String {
+    // Howard Hinnant's civil_from_days algorithm.
+    let z = day + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::date_of;
+
+    #[test]
+    fn date_of_epoch() {
+        assert_eq!(date_of(0), "1970-01-01");
+        assert_eq!(date_of(-1), "1969-12-31");
+    }
+
+    #[test]
+    fn date_of_leap_years() {
+        // 2000 is divisible by 400, so it has a 29 February.
+        assert_eq!(date_of(11_015), "2000-02-28");
+        assert_eq!(date_of(11_016), "2000-02-29");
+        assert_eq!(date_of(11_017), "2000-03-01");
+        assert_eq!(date_of(19_782), "2024-02-29");
+        assert_eq!(date_of(20_088), "2024-12-31");
+    }
+
+    #[test]
+    fn date_of_century_years() {
+        // 1900 and 2100 are divisible by 100 but not 400, so they are not.
+        assert_eq!(date_of(-25_509), "1900-02-28");
+        assert_eq!(date_of(-25_508), "1900-03-01");
+        assert_eq!(date_of(47_540), "2100-02-28");
+        assert_eq!(date_of(47_541), "2100-03-01");
+    }
+}
@@ -0,0 +1,206 @@
+use crate::params::Params;
+use crate::pb::contract::v1 as contract;
+use crate::watch_list::retain_activity;
+use anyhow::{anyhow, Context};
+use ethabi::param_type::Reader;
+use ethabi::{EventParam, Hash, ParamType, RawLog};
+use substreams::store::{StoreGet, StoreGetInt64, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsInt64};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// Factory whose creation event announces the contracts to track, set
+/// through the `factory`, `creation_event` and `address_param` params.
+struct Factory {
+    address: Vec<u8>,
+    topic: Hash,
+    event: ethabi::Event,
+    address_param: String,
+}
+
+impl Factory {
+    fn from_params(params: &str) -> Result<Option<Self>, substreams::errors::Error> {
+        let params = Params::parse(params)?;
+        let Some(address) = params.get("factory") else {
+            return Ok(None);
+        };
+
+        let address = Hex::decode(address).ok()
+            .filter(|address| address.len() == 20)
+            .ok_or_else(|| anyhow!("invalid address {:?} in param factory", address))?;
+        let event = params.get("creation_event").ok_or_else(|| anyhow!("missing param creation_event"))?;
+        let event = parse_event(event).with_context(|| format!("invalid param creation_event {:?}", event))?;
+        let address_param = match params.get("address_param") {
+            Some(name) => name.to_string(),
+            None => event.inputs.iter()
+                .find(|input| input.kind == ParamType::Address)
+                .map(|input| input.name.clone())
+                .ok_or_else(|| anyhow!("event {} has no address param", event.name))?,
+        };
+        if !event.inputs.iter().any(|input| input.name == address_param && input.kind == ParamType::Address) {
+            return Err(anyhow!("event {} has no address param named {:?}", event.name, address_param));
+        }
+
+        Ok(Some(Factory { address, topic: event.signature(), event, address_param }))
+    }
+
+    /// Address of the contract created by `log`, if it is a creation event of
+    /// the factory.
+    fn created(&self, log: &eth::Log) -> Option<Vec<u8>> {
+        if log.address != self.address || log.topics.first().map(Vec::as_slice) != Some(self.topic.as_bytes()) {
+            return None;
+        }
+
+        let raw = RawLog {
+            topics: log.topics.iter().map(|topic| Hash::from_slice(topic)).collect(),
+            data: log.data.clone(),
+        };
+        self.event.parse_log(raw).ok()?
+            .params.into_iter()
+            .find(|param| param.name == self.address_param)?
+            .value.into_address()
+            .map(|address| address.as_bytes().to_vec())
+    }
+}
+
+/// Parses a human-readable event signature, such as
+/// `WalletCreated(address indexed wallet, address owner)`.
+fn parse_event(signature: &str) -> Result<ethabi::Event, substreams::errors::Error> {
+    let (name, inputs) = signature.trim()
+        .strip_suffix(')')
+        .and_then(|signature| signature.split_once('('))
+        .ok_or_else(|| anyhow!("expected Name(type [indexed] name, ...)"))?;
+
+    let inputs = inputs.split(',').map(str::trim).filter(|input| !input.is_empty()).enumerate()
+        .map(|(index, input)| {
+            let mut words = input.split_whitespace();
+            let word = words.next().unwrap_or_default();
+            let kind = Reader::read(word).map_err(|e| anyhow!("{}", e))?;
+            // ethabi reads any unknown type as an enum, that is a `uint8`.
+            let base = word.split('[').next().unwrap_or_default();
+            if base != "uint8" && Reader::read(base).ok() == Some(ParamType::Uint(8)) {
+                return Err(anyhow!("unknown type {:?}", word));
+            }
+            let mut name = words.next();
+            let indexed = name == Some("indexed");
+            if indexed {
+                name = words.next();
+            }
+
+            Ok(EventParam {
+                name: name.map(str::to_string).unwrap_or_else(|| format!("param{}", index)),
+                kind,
+                indexed,
+            })
+        })
+        .collect::<Result<Vec<_>, substreams::errors::Error>>()?;
+
+    Ok(ethabi::Event { name: name.trim().to_string(), inputs, anonymous: false })
+}
+
+pub fn discovered_key(address: &[u8]) -> String {
+    format!("contract:{}", Hex(address))
+}
+
+/// Emits the contracts created by the factory named in the params, from its
+/// `creation_event`, taking the address from the `address_param` argument or
+/// else the first address argument. Nothing is discovered when no `factory`
+/// is set.
+#[substreams::handlers::map]
+fn map_discovered_contracts(params: String, blk: eth::Block) -> Result<contract::DiscoveredContracts, substreams::errors::Error> {
+    let Some(factory) = Factory::from_params(&params)? else {
+        substreams::skip_empty_output();
+        return Ok(contract::DiscoveredContracts::default());
+    };
+
+    let discovered_contracts = blk.receipts()
+        .flat_map(|view| view.receipt.logs.iter().map(move |log| (view, log)))
+        .filter_map(|(view, log)| {
+            Some(contract::DiscoveredContract {
+                address: factory.created(log)?,
+                factory: factory.address.clone(),
+                tx_hash: Hex(&view.transaction.hash).to_string(),
+                block_time: Some(blk.timestamp().to_owned()),
+                block_number: blk.number,
+                ordinal: log.ordinal,
+            })
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::DiscoveredContracts { discovered_contracts })
+}
+
+/// Records the block in which each contract was discovered.
+#[substreams::handlers::store]
+fn store_discovered_contracts(discovered: contract::DiscoveredContracts, store: StoreSetIfNotExistsInt64) {
+    for contract in discovered.discovered_contracts {
+        store.set_if_not_exists(contract.ordinal, discovered_key(&contract.address), &(contract.block_number as i64));
+    }
+}
+
+/// Filters the events and calls of the tracked contract down to the
+/// activity of the discovered contracts, such as the transfers a discovered
+/// wallet sends or receives and the calls it makes, like `watch_list` does
+/// for a fixed list.
+#[substreams::handlers::map]
+fn map_discovered_events_calls(
+    events: contract::Events,
+    calls: contract::Calls,
+    discovered: StoreGetInt64,
+) -> Result<contract::EventsCalls, substreams::errors::Error> {
+    let events_calls = retain_activity(events, calls, |address| discovered.has_last(discovered_key(address)));
+
+    substreams::skip_empty_output();
+    Ok(events_calls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_event, Factory};
+    use ethabi::ParamType;
+
+    const FACTORY: &str = "factory=0x1111111111111111111111111111111111111111";
+
+    #[test]
+    fn parse_indexed_and_unnamed_params() {
+        let event = parse_event(" WalletCreated(address indexed wallet, address, uint256 salt) ").unwrap();
+        assert_eq!(event.name, "WalletCreated");
+        let types = [ParamType::Address, ParamType::Address, ParamType::Uint(256)];
+        assert_eq!(event.signature(), ethabi::long_signature("WalletCreated", &types));
+
+        let inputs: Vec<(&str, bool)> = event.inputs.iter().map(|input| (input.name.as_str(), input.indexed)).collect();
+        assert_eq!(inputs, vec![("wallet", true), ("param1", false), ("salt", false)]);
+    }
+
+    #[test]
+    fn parse_invalid_signatures() {
+        assert!(parse_event("WalletCreated(wallet indexed)").is_err());
+        assert!(parse_event("WalletCreated(adress[] wallets)").is_err());
+        assert!(parse_event("WalletCreated(address wallet").is_err());
+        assert!(parse_event("WalletCreated").is_err());
+    }
+
+    #[test]
+    fn factory_address_param() {
+        let params = format!("{};creation_event=Created(uint256 id, address indexed owner, address wallet)", FACTORY);
+        assert_eq!(Factory::from_params(&params).unwrap().unwrap().address_param, "owner");
+
+        let params = format!("{};creation_event=Created(address indexed owner, address wallet);address_param=wallet", FACTORY);
+        assert_eq!(Factory::from_params(&params).unwrap().unwrap().address_param, "wallet");
+
+        for params in [
+            format!("{};creation_event=Created(address owner);address_param=wallet", FACTORY),
+            format!("{};creation_event=Created(address owner, uint256 wallet);address_param=wallet", FACTORY),
+            format!("{};creation_event=Created(uint256 id)", FACTORY),
+            format!("{};address_param=wallet", FACTORY),
+            "factory=0x1234;creation_event=Created(address owner)".to_string(),
+        ] {
+            assert!(Factory::from_params(&params).is_err(), "{}", params);
+        }
+    }
+
+    #[test]
+    fn no_factory() {
+        assert!(Factory::from_params("").unwrap().is_none());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::large_transfers::transfer_kind;
+use crate::pb::contract::v1 as contract;
+
+/// Ordinal range of a successful call that can move funds.
+struct CallSpan<'a> {
+    function: &'static str,
+    begin_ordinal: u64,
+    end_ordinal: u64,
+    caller: &'a [u8],
+}
+
+macro_rules! call_spans {
+    ($calls:expr, $spans:expr, $($field:ident => $function:literal,)*) => {
+        $(
+            $spans.extend($calls.$field.iter()
+                .filter(|call| call.call_success)
+                .map(|call| CallSpan {
+                    function: $function,
+                    begin_ordinal: call.call_ordinal,
+                    end_ordinal: call.call_end_ordinal,
+                    caller: &call.call_caller,
+                }));
+        )*
+    };
+}
+
+fn call_spans(calls: &contract::Calls) -> Vec<CallSpan<'_>> {
+    let mut spans = Vec::new();
+    call_spans!(calls, spans,
+        usdc_call_transfers => "transfer",
+        usdc_call_transfer_froms => "transferFrom",
+        usdc_call_transfer_with_authorization_1s => "transferWithAuthorization",
+        usdc_call_transfer_with_authorization_2s => "transferWithAuthorization",
+        usdc_call_receive_with_authorization_1s => "receiveWithAuthorization",
+        usdc_call_receive_with_authorization_2s => "receiveWithAuthorization",
+        usdc_call_mints => "mint",
+        usdc_call_burns => "burn",
+        usdc_call_rescue_erc_20s => "rescueERC20",
+    );
+
+    spans
+}
+
+/// Pairs each `Transfer` event with the calls whose ordinal range contains
+/// it. The outermost call is the entry point that moved the funds, such as a
+/// `rescueERC20` wrapping a `transfer`, while the innermost one emitted the
+/// log. Both are left empty when no matching call was traced.
+#[substreams::handlers::map]
+fn map_enriched_transfers(
+    events: contract::Events,
+    calls: contract::Calls,
+) -> Result<contract::EnrichedTransfers, substreams::errors::Error> {
+    let spans = call_spans(&calls);
+
+    let enriched_transfers = events.usdc_transfers.into_iter()
+        .map(|transfer| {
+            let enclosing = || spans.iter()
+                .filter(|span| span.begin_ordinal <= transfer.evt_ordinal && transfer.evt_ordinal <= span.end_ordinal);
+            let entry_point = enclosing().min_by_key(|span| span.begin_ordinal);
+            let emitting = enclosing().max_by_key(|span| span.begin_ordinal);
+
+            contract::EnrichedTransfer {
+                kind: transfer_kind(&transfer.from, &transfer.to) as i32,
+                entry_point: entry_point.map(|span| span.function.to_string()).unwrap_or_default(),
+                entry_point_ordinal: entry_point.map(|span| span.begin_ordinal).unwrap_or_default(),
+                entry_point_caller: entry_point.map(|span| span.caller.to_vec()).unwrap_or_default(),
+                emitting_function: emitting.map(|span| span.function.to_string()).unwrap_or_default(),
+                evt_tx_hash: transfer.evt_tx_hash,
+                evt_index: transfer.evt_index,
+                evt_block_time: transfer.evt_block_time,
+                evt_block_number: transfer.evt_block_number,
+                evt_ordinal: transfer.evt_ordinal,
+                from: transfer.from,
+                to: transfer.to,
+                value: transfer.value,
+            }
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::EnrichedTransfers { enriched_transfers })
+}
@@ -0,0 +1,106 @@
+use crate::params::{Params, Selection};
+use crate::pb::contract::v1 as contract;
+use anyhow::anyhow;
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// `Transfer(address,address,uint256)`.
+const TRANSFER_TOPIC: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+/// `Approval(address,address,uint256)`.
+const APPROVAL_TOPIC: [u8; 32] = hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
+/// Names of the events decoded by `map_erc20_events`.
+const ERC20_EVENT_NAMES: [&str; 2] = ["Transfer", "Approval"];
+
+/// Token addresses given through the comma-separated `tokens` param.
+fn tokens(params: &Params) -> Result<Vec<Vec<u8>>, substreams::errors::Error> {
+    let tokens = params.get("tokens").ok_or_else(|| anyhow!("missing param tokens"))?;
+
+    tokens.split(',').map(str::trim).filter(|token| !token.is_empty())
+        .map(|token| match Hex::decode(token) {
+            Ok(address) if address.len() == 20 => Ok(address),
+            _ => Err(anyhow!("invalid address {:?} in param tokens", token)),
+        })
+        .collect()
+}
+
+/// Decodes the indexed addresses and the amount of a standard `Transfer` or
+/// `Approval` log. Logs with another layout, such as ERC-721 transfers with
+/// an indexed token ID, are skipped.
+fn decode(log: &eth::Log) -> Option<(Vec<u8>, Vec<u8>, String)> {
+    if log.topics.len() != 3 || log.data.len() != 32 {
+        return None;
+    }
+
+    Some((log.topics[1][12..].to_vec(), log.topics[2][12..].to_vec(), BigInt::from_unsigned_bytes_be(&log.data).to_string()))
+}
+
+/// Decodes `Transfer` and `Approval` events of any ERC-20 token listed in
+/// the `tokens` param, matched by topic alone, into the same messages as
+/// the tracked contract, so that the sinks of `map_events_calls` read them
+/// through `map_erc20_events_calls`. The stores, `graph_out` and the
+/// analytics modules only read `map_events`. The `events` param selects
+/// which of the two are decoded, as for `map_events`.
+#[substreams::handlers::map]
+fn map_erc20_events(params: String, blk: eth::Block) -> Result<contract::Events, substreams::errors::Error> {
+    let params = Params::parse(&params)?;
+    let selection = Selection::parse(&params, "events", &ERC20_EVENT_NAMES)?;
+    let tokens = tokens(&params)?;
+    let mut events = contract::Events::default();
+
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter().filter(|log| tokens.contains(&log.address)) {
+            let Some(topic) = log.topics.first() else {
+                continue;
+            };
+
+            if *topic == TRANSFER_TOPIC && selection.includes("Transfer") {
+                if let Some((from, to, value)) = decode(log) {
+                    events.usdc_transfers.push(contract::UsdcTransfer {
+                        evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                        evt_index: log.block_index,
+                        evt_block_time: Some(blk.timestamp().to_owned()),
+                        evt_block_number: blk.number,
+                        evt_ordinal: log.ordinal,
+                        evt_block_hash: Hex(&blk.hash).to_string(),
+                        block_timestamp_seconds: blk.timestamp_seconds(),
+                        evt_address: log.address.clone(),
+                        from,
+                        to,
+                        value,
+                    });
+                }
+            } else if *topic == APPROVAL_TOPIC && selection.includes("Approval") {
+                if let Some((owner, spender, value)) = decode(log) {
+                    events.usdc_approvals.push(contract::UsdcApproval {
+                        evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                        evt_index: log.block_index,
+                        evt_block_time: Some(blk.timestamp().to_owned()),
+                        evt_block_number: blk.number,
+                        evt_ordinal: log.ordinal,
+                        evt_block_hash: Hex(&blk.hash).to_string(),
+                        block_timestamp_seconds: blk.timestamp_seconds(),
+                        evt_address: log.address.clone(),
+                        owner,
+                        spender,
+                        value,
+                    });
+                }
+            }
+        }
+    }
+
+    substreams::skip_empty_output();
+    Ok(events)
+}
+
+/// Wraps the `map_erc20_events` output for the sinks of `map_events_calls`.
+#[substreams::handlers::map]
+fn map_erc20_events_calls(events: contract::Events) -> Result<contract::EventsCalls, substreams::errors::Error> {
+    Ok(contract::EventsCalls {
+        events: Some(events),
+        calls: Some(contract::Calls::default()),
+    })
+}
@@ -0,0 +1,38 @@
+/// Invokes `$callback!` with every repeated field of `contract::Events`, each
+/// paired with its `activity::Payload` variant and the name of the event it
+/// decodes.
+macro_rules! for_each_event_field {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback!($($args)*
+            usdc_admin_changeds: UsdcAdminChanged => "AdminChanged",
+            usdc_approvals: UsdcApproval => "Approval",
+            usdc_authorization_canceleds: UsdcAuthorizationCanceled => "AuthorizationCanceled",
+            usdc_authorization_useds: UsdcAuthorizationUsed => "AuthorizationUsed",
+            usdc_blacklisteds: UsdcBlacklisted => "Blacklisted",
+            usdc_blacklister_changeds: UsdcBlacklisterChanged => "BlacklisterChanged",
+            usdc_burns: UsdcBurn => "Burn",
+            usdc_master_minter_changeds: UsdcMasterMinterChanged => "MasterMinterChanged",
+            usdc_mints: UsdcMint => "Mint",
+            usdc_minter_configureds: UsdcMinterConfigured => "MinterConfigured",
+            usdc_minter_removeds: UsdcMinterRemoved => "MinterRemoved",
+            usdc_ownership_transferreds: UsdcOwnershipTransferred => "OwnershipTransferred",
+            usdc_pauses: UsdcPause => "Pause",
+            usdc_pauser_changeds: UsdcPauserChanged => "PauserChanged",
+            usdc_rescuer_changeds: UsdcRescuerChanged => "RescuerChanged",
+            usdc_transfers: UsdcTransfer => "Transfer",
+            usdc_un_blacklisteds: UsdcUnBlacklisted => "UnBlacklisted",
+            usdc_unpauses: UsdcUnpause => "Unpause",
+            usdc_upgradeds: UsdcUpgraded => "Upgraded",
+        )
+    };
+}
+pub(crate) use for_each_event_field;
+
+macro_rules! event_names {
+    ($($field:ident: $variant:ident => $event:literal,)*) => {
+        [$($event),*]
+    };
+}
+
+/// Names of the contract events decoded into `contract::Events`.
+pub const EVENT_NAMES: [&str; 19] = for_each_event_field!(event_names!());
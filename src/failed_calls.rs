@@ -0,0 +1,234 @@
+use crate::calls::function_name;
+use crate::pb::contract::v1 as contract;
+use crate::USDC_TRACKED_CONTRACT;
+use contract::RevertKind;
+use ethabi::ParamType;
+use hex_literal::hex;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// Selector of `Error(string)`, the data of `require` and `revert` with a
+/// message.
+const ERROR_SELECTOR: [u8; 4] = hex!("08c379a0");
+/// Selector of `Panic(uint256)`, the data of failed assertions and checked
+/// arithmetic since Solidity 0.8.
+const PANIC_SELECTOR: [u8; 4] = hex!("4e487b71");
+
+/// Revert strings of the FiatToken contracts, with the kind they map to.
+const REVERT_REASONS: [(&str, RevertKind); 32] = [
+    ("Blacklistable: account is blacklisted", RevertKind::Blacklisted),
+    ("Pausable: paused", RevertKind::Paused),
+    ("ERC20: transfer amount exceeds balance", RevertKind::InsufficientBalance),
+    ("FiatToken: burn amount exceeds balance", RevertKind::InsufficientBalance),
+    ("ERC20: transfer amount exceeds allowance", RevertKind::InsufficientAllowance),
+    ("ERC20: decreased allowance below zero", RevertKind::InsufficientAllowance),
+    ("ERC20: transfer from the zero address", RevertKind::ZeroAddress),
+    ("ERC20: transfer to the zero address", RevertKind::ZeroAddress),
+    ("ERC20: approve from the zero address", RevertKind::ZeroAddress),
+    ("ERC20: approve to the zero address", RevertKind::ZeroAddress),
+    ("FiatToken: mint to the zero address", RevertKind::ZeroAddress),
+    ("FiatToken: new masterMinter is the zero address", RevertKind::ZeroAddress),
+    ("Pausable: new pauser is the zero address", RevertKind::ZeroAddress),
+    ("Blacklistable: new blacklister is the zero address", RevertKind::ZeroAddress),
+    ("Rescuable: new rescuer is the zero address", RevertKind::ZeroAddress),
+    ("Ownable: new owner is the zero address", RevertKind::ZeroAddress),
+    ("FiatToken: mint amount not greater than 0", RevertKind::InvalidAmount),
+    ("FiatToken: burn amount not greater than 0", RevertKind::InvalidAmount),
+    ("FiatTokenV2: invalid signature", RevertKind::InvalidSignature),
+    ("EIP2612: invalid signature", RevertKind::InvalidSignature),
+    ("FiatTokenV2: permit is expired", RevertKind::Expired),
+    ("FiatTokenV2: authorization is expired", RevertKind::Expired),
+    ("FiatTokenV2: authorization is not yet valid", RevertKind::AuthorizationNotYetValid),
+    ("FiatTokenV2: authorization is used or canceled", RevertKind::AuthorizationUsed),
+    ("FiatTokenV2: caller must be the payee", RevertKind::CallerNotPayee),
+    ("Ownable: caller is not the owner", RevertKind::UnauthorizedCaller),
+    ("Pausable: caller is not the pauser", RevertKind::UnauthorizedCaller),
+    ("Blacklistable: caller is not the blacklister", RevertKind::UnauthorizedCaller),
+    ("Rescuable: caller is not the rescuer", RevertKind::UnauthorizedCaller),
+    ("FiatToken: caller is not a minter", RevertKind::UnauthorizedCaller),
+    ("FiatToken: caller is not the masterMinter", RevertKind::UnauthorizedCaller),
+    ("FiatToken: mint amount exceeds minterAllowance", RevertKind::MintAllowanceExceeded),
+];
+
+fn panic_description(code: u64) -> &'static str {
+    match code {
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array",
+        0x31 => "pop on empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to zero-initialized function",
+        _ => "unknown panic code",
+    }
+}
+
+/// Decodes the revert data of a failed call into its kind and reason.
+fn revert_reason(call: &eth::Call) -> (RevertKind, String) {
+    if !call.status_failed {
+        return (RevertKind::ParentReverted, String::new());
+    }
+
+    let data = &call.return_data;
+    if data.len() < 4 {
+        return (RevertKind::NoData, call.failure_reason.clone());
+    }
+
+    let (selector, arguments) = data.split_at(4);
+    if selector == ERROR_SELECTOR {
+        if let Some(reason) = ethabi::decode(&[ParamType::String], arguments).ok().and_then(|tokens| tokens.into_iter().next()?.into_string()) {
+            let kind = REVERT_REASONS.iter()
+                .find(|(known, _)| *known == reason)
+                .map(|(_, kind)| *kind)
+                .unwrap_or(RevertKind::Other);
+            return (kind, reason);
+        }
+    } else if selector == PANIC_SELECTOR {
+        if let Some(code) = ethabi::decode(&[ParamType::Uint(256)], arguments).ok().and_then(|tokens| tokens.into_iter().next()?.into_uint()) {
+            let code = code.low_u64();
+            return (RevertKind::Panic, format!("Panic(0x{:02x}): {}", code, panic_description(code)));
+        }
+    }
+
+    (RevertKind::Other, format!("0x{}", Hex(data)))
+}
+
+/// Reverted calls to a state-changing function of the tracked contract, in
+/// every transaction of the block, failed ones included: a transaction whose
+/// top-level call reverts is failed as a whole.
+fn failed_calls(blk: &eth::Block) -> Vec<contract::FailedCall> {
+    blk.transaction_traces.iter()
+        .flat_map(|tx| {
+            tx.calls.iter()
+                .filter(|call| call.address == USDC_TRACKED_CONTRACT && call.state_reverted)
+                .filter_map(|call| {
+                    let function = function_name(call)?;
+                    let (kind, reason) = revert_reason(call);
+
+                    Some(contract::FailedCall {
+                        tx_hash: Hex(&tx.hash).to_string(),
+                        block_time: Some(blk.timestamp().to_owned()),
+                        block_number: blk.number,
+                        ordinal: call.begin_ordinal,
+                        function: function.to_string(),
+                        caller: call.caller.clone(),
+                        kind: kind as i32,
+                        reason,
+                        return_data: call.return_data.clone(),
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Emits every reverted call to a state-changing function of the tracked
+/// contract, failed transactions included, with the reason decoded from its
+/// revert data: the message of an `Error(string)`, mapped to a kind for the
+/// FiatToken revert strings, or the code of a `Panic(uint256)`. Calls that
+/// succeeded inside a reverted parent are reported as `PARENT_REVERTED`, and
+/// failures without revert data, such as running out of gas, carry the
+/// tracer's failure reason.
+#[substreams::handlers::map]
+fn map_failed_calls(blk: eth::Block) -> Result<contract::FailedCalls, substreams::errors::Error> {
+    let failed_calls = failed_calls(&blk);
+
+    substreams::skip_empty_output();
+    Ok(contract::FailedCalls { failed_calls })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::usdc_contract::functions;
+    use crate::test_blocks::{block, BLOCK_NUMBER};
+    use ethabi::Token;
+    use substreams::scalar::BigInt;
+
+    const SENDER: [u8; 20] = hex!("1111111111111111111111111111111111111111");
+    const RECIPIENT: [u8; 20] = hex!("2222222222222222222222222222222222222222");
+
+    fn error_data(reason: &str) -> Vec<u8> {
+        [ERROR_SELECTOR.to_vec(), ethabi::encode(&[Token::String(reason.to_string())])].concat()
+    }
+
+    #[test]
+    fn reverted_top_level_transfer() {
+        let transfer = functions::Transfer { to: RECIPIENT.to_vec(), value: BigInt::from(1_000_000) };
+        let tx = eth::TransactionTrace {
+            from: SENDER.to_vec(),
+            to: USDC_TRACKED_CONTRACT.to_vec(),
+            status: eth::TransactionTraceStatus::Reverted as i32,
+            calls: vec![eth::Call {
+                index: 1,
+                depth: 0,
+                caller: SENDER.to_vec(),
+                address: USDC_TRACKED_CONTRACT.to_vec(),
+                input: transfer.encode(),
+                return_data: error_data("ERC20: transfer amount exceeds balance"),
+                state_reverted: true,
+                status_failed: true,
+                status_reverted: true,
+                begin_ordinal: 3,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let failed_calls = failed_calls(&block(tx));
+        assert_eq!(failed_calls.len(), 1);
+        let failed_call = &failed_calls[0];
+        assert_eq!(failed_call.function, "transfer");
+        assert_eq!(failed_call.caller, SENDER.to_vec());
+        assert_eq!(failed_call.kind, RevertKind::InsufficientBalance as i32);
+        assert_eq!(failed_call.reason, "ERC20: transfer amount exceeds balance");
+        assert_eq!(failed_call.ordinal, 3);
+        assert_eq!(failed_call.block_number, BLOCK_NUMBER);
+    }
+
+    fn failed_call(return_data: Vec<u8>) -> eth::Call {
+        eth::Call {
+            return_data,
+            state_reverted: true,
+            status_failed: true,
+            status_reverted: true,
+            failure_reason: "execution reverted".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn revert_reason_error_string() {
+        let call = failed_call(error_data("Blacklistable: account is blacklisted"));
+        assert_eq!(revert_reason(&call), (RevertKind::Blacklisted, "Blacklistable: account is blacklisted".to_string()));
+
+        let call = failed_call(error_data("custom message"));
+        assert_eq!(revert_reason(&call), (RevertKind::Other, "custom message".to_string()));
+    }
+
+    #[test]
+    fn revert_reason_panic_code() {
+        let data = [PANIC_SELECTOR.to_vec(), ethabi::encode(&[Token::Uint(0x11.into())])].concat();
+        assert_eq!(
+            revert_reason(&failed_call(data)),
+            (RevertKind::Panic, "Panic(0x11): arithmetic overflow or underflow".to_string()),
+        );
+
+        let data = [PANIC_SELECTOR.to_vec(), ethabi::encode(&[Token::Uint(0x99.into())])].concat();
+        assert_eq!(revert_reason(&failed_call(data)), (RevertKind::Panic, "Panic(0x99): unknown panic code".to_string()));
+    }
+
+    #[test]
+    fn revert_reason_without_payload() {
+        assert_eq!(revert_reason(&failed_call(Vec::new())), (RevertKind::NoData, "execution reverted".to_string()));
+        assert_eq!(revert_reason(&failed_call(vec![0xde, 0xad, 0xbe, 0xef])), (RevertKind::Other, "0xdeadbeef".to_string()));
+        assert_eq!(revert_reason(&failed_call(ERROR_SELECTOR.to_vec())), (RevertKind::Other, "0x08c379a0".to_string()));
+    }
+
+    #[test]
+    fn revert_reason_parent_reverted() {
+        let call = eth::Call { state_reverted: true, ..Default::default() };
+        assert_eq!(revert_reason(&call), (RevertKind::ParentReverted, String::new()));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::abi::usdc_contract::events;
+use crate::calls::function_name;
+use crate::pb::contract::v1 as contract;
+use crate::{USDC_TRACKED_CONTRACT, ZERO_ADDRESS};
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+fn big_int(value: &Option<eth::BigInt>) -> Option<BigInt> {
+    value.as_ref().map(Into::into)
+}
+
+/// Price paid per unit of gas. Transactions with EIP-1559 fees pay the base
+/// fee plus their priority fee, capped by their max fee.
+fn effective_gas_price(tx: &eth::TransactionTrace, base_fee_per_gas: &Option<BigInt>) -> BigInt {
+    let gas_price = big_int(&tx.gas_price).unwrap_or_else(|| BigInt::from(0));
+
+    match (base_fee_per_gas, big_int(&tx.max_fee_per_gas), big_int(&tx.max_priority_fee_per_gas)) {
+        (Some(base_fee_per_gas), Some(max_fee_per_gas), Some(max_priority_fee_per_gas)) => {
+            let price = base_fee_per_gas + max_priority_fee_per_gas;
+            if price > max_fee_per_gas { max_fee_per_gas } else { price }
+        }
+        _ => gas_price,
+    }
+}
+
+/// Whether the transaction decoded a tracked call, or emitted an event of
+/// the tracked contract.
+fn is_tracked(tx: &eth::TransactionTrace) -> bool {
+    tx.calls.iter().any(|call| {
+        call.address == USDC_TRACKED_CONTRACT
+            && (function_name(call).is_some() || (!call.state_reverted && call.logs.iter().any(|log| log.address == USDC_TRACKED_CONTRACT)))
+    })
+}
+
+/// Senders of the transfers made in the transaction, mints excluded.
+fn usdc_senders(tx: &eth::TransactionTrace) -> Vec<Vec<u8>> {
+    let mut senders: Vec<Vec<u8>> = Vec::new();
+    let transfers = tx.calls.iter()
+        .filter(|call| !call.state_reverted)
+        .flat_map(|call| call.logs.iter())
+        .filter(|log| log.address == USDC_TRACKED_CONTRACT)
+        .filter_map(events::Transfer::match_and_decode);
+    for transfer in transfers {
+        if transfer.from != ZERO_ADDRESS && !senders.contains(&transfer.from) {
+            senders.push(transfer.from);
+        }
+    }
+
+    senders
+}
+
+/// Emits the gas cost of every transaction that calls a tracked function or
+/// emits an event of the tracked contract, failed transactions included: gas
+/// used, effective gas price, total fee in wei and its split between the
+/// burned base fee and the priority fee paid to the block producer. A
+/// transaction is `sponsored` when its fee payer, the transaction sender,
+/// is not among the senders of its transfers, as with meta-transactions
+/// submitted by a relayer.
+#[substreams::handlers::map]
+fn map_transaction_fees(blk: eth::Block) -> Result<contract::TransactionFees, substreams::errors::Error> {
+    let base_fee_per_gas = blk.header.as_ref().and_then(|header| big_int(&header.base_fee_per_gas));
+
+    let transaction_fees = blk.transaction_traces.iter()
+        .filter(|tx| is_tracked(tx))
+        .map(|tx| {
+            let gas_used = BigInt::from(tx.gas_used);
+            let effective_gas_price = effective_gas_price(tx, &base_fee_per_gas);
+            let base_fee_per_gas = base_fee_per_gas.clone().unwrap_or_else(|| BigInt::from(0));
+            let priority_fee_per_gas = effective_gas_price.clone() - base_fee_per_gas.clone();
+            let usdc_senders = usdc_senders(tx);
+
+            contract::TransactionFee {
+                tx_hash: Hex(&tx.hash).to_string(),
+                block_time: Some(blk.timestamp().to_owned()),
+                block_number: blk.number,
+                fee_payer: tx.from.clone(),
+                gas_used: tx.gas_used,
+                total_fee: (gas_used.clone() * effective_gas_price.clone()).to_string(),
+                base_fee: (gas_used.clone() * base_fee_per_gas.clone()).to_string(),
+                priority_fee: (gas_used * priority_fee_per_gas.clone()).to_string(),
+                effective_gas_price: effective_gas_price.to_string(),
+                base_fee_per_gas: base_fee_per_gas.to_string(),
+                priority_fee_per_gas: priority_fee_per_gas.to_string(),
+                sponsored: !usdc_senders.is_empty() && !usdc_senders.contains(&tx.from),
+                usdc_senders,
+            }
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::TransactionFees { transaction_fees })
+}
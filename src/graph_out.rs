@@ -0,0 +1,474 @@
+use crate::abi::usdc_contract::functions::{IsMinter, MinterAllowance};
+use crate::addresses::{address_key, blacklist_changes, blacklist_key, touched_addresses};
+use crate::balances::balance_key;
+use crate::minters::{burned_key, minted_key, touched_minters};
+use crate::pb::contract::v1 as contract;
+use crate::USDC_TRACKED_CONTRACT;
+use substreams::pb::substreams::Clock;
+use substreams::store::{StoreGet, StoreGetBigInt, StoreGetInt64};
+use substreams::Hex;
+use substreams_entity_change::pb::entity::EntityChanges;
+use substreams_entity_change::tables::Tables;
+use substreams_ethereum::rpc::RpcBatch;
+use std::str::FromStr;
+
+/// Writes one entity per event, identified by `<tx_hash>-<log_index>`.
+macro_rules! event_entities {
+    ($events:expr, $tables:expr, $($field:ident => $entity:literal { $($column:literal: $param:ident => $convert:ident,)* },)*) => {
+        $(
+            for event in $events.$field {
+                $tables
+                    .create_row($entity, format!("{}-{}", event.evt_tx_hash, event.evt_index))
+                    .set("timestamp", event.block_timestamp_seconds as i32)
+                    .set("blockNumber", event.evt_block_number as i32)
+                    .set("blockHash", event.evt_block_hash)
+                    .set("txHash", event.evt_tx_hash)
+                    .set("logIndex", event.evt_index as i32)
+                    $( .set($column, $convert(event.$param)) )*;
+            }
+        )*
+    };
+}
+
+/// Writes one entity per call, identified by `<tx_hash>-call-<ordinal>`.
+macro_rules! call_entities {
+    ($calls:expr, $tables:expr, $($field:ident => $entity:literal { $($column:literal: $param:ident => $convert:ident,)* },)*) => {
+        $(
+            for call in $calls.$field {
+                $tables
+                    .create_row($entity, format!("{}-call-{}", call.call_tx_hash, call.call_ordinal))
+                    .set("timestamp", call.block_timestamp_seconds as i32)
+                    .set("blockNumber", call.call_block_number as i32)
+                    .set("blockHash", call.call_block_hash)
+                    .set("txHash", call.call_tx_hash)
+                    .set("ordinal", call.call_ordinal as i32)
+                    .set("caller", hex(call.call_caller))
+                    .set("success", call.call_success)
+                    $( .set($column, $convert(call.$param)) )*;
+            }
+        )*
+    };
+}
+
+fn seconds(time: &Option<prost_types::Timestamp>) -> i32 {
+    time.as_ref().map(|t| t.seconds).unwrap_or_default() as i32
+}
+
+fn hex(address: Vec<u8>) -> String {
+    format!("0x{}", Hex(address))
+}
+
+fn hexes(addresses: Vec<Vec<u8>>) -> Vec<String> {
+    addresses.into_iter().map(hex).collect()
+}
+
+fn int(value: u64) -> i32 {
+    value as i32
+}
+
+fn plain<T>(value: T) -> T {
+    value
+}
+
+fn event_entities(events: contract::Events, tables: &mut Tables) {
+    event_entities!(events, tables,
+        usdc_admin_changeds => "AdminChangedEvent" {
+            "previousAdmin": previous_admin => hex,
+            "newAdmin": new_admin => hex,
+        },
+        usdc_approvals => "ApprovalEvent" {
+            "owner": owner => hex,
+            "spender": spender => hex,
+            "value": value => plain,
+        },
+        usdc_authorization_canceleds => "AuthorizationCanceledEvent" {
+            "authorizer": authorizer => hex,
+            "nonce": nonce => hex,
+        },
+        usdc_authorization_useds => "AuthorizationUsedEvent" {
+            "authorizer": authorizer => hex,
+            "nonce": nonce => hex,
+        },
+        usdc_blacklisteds => "BlacklistedEvent" {
+            "account": u_account => hex,
+        },
+        usdc_blacklister_changeds => "BlacklisterChangedEvent" {
+            "newBlacklister": new_blacklister => hex,
+        },
+        usdc_burns => "BurnEvent" {
+            "burner": burner => hex,
+            "amount": amount => plain,
+        },
+        usdc_master_minter_changeds => "MasterMinterChangedEvent" {
+            "newMasterMinter": new_master_minter => hex,
+        },
+        usdc_mints => "MintEvent" {
+            "minter": minter => hex,
+            "to": to => hex,
+            "amount": amount => plain,
+        },
+        usdc_minter_configureds => "MinterConfiguredEvent" {
+            "minter": minter => hex,
+            "minterAllowedAmount": minter_allowed_amount => plain,
+        },
+        usdc_minter_removeds => "MinterRemovedEvent" {
+            "oldMinter": old_minter => hex,
+        },
+        usdc_ownership_transferreds => "OwnershipTransferredEvent" {
+            "previousOwner": previous_owner => hex,
+            "newOwner": new_owner => hex,
+        },
+        usdc_pauses => "PauseEvent" {},
+        usdc_pauser_changeds => "PauserChangedEvent" {
+            "newAddress": new_address => hex,
+        },
+        usdc_rescuer_changeds => "RescuerChangedEvent" {
+            "newRescuer": new_rescuer => hex,
+        },
+        usdc_transfers => "TransferEvent" {
+            "from": from => hex,
+            "to": to => hex,
+            "value": value => plain,
+        },
+        usdc_un_blacklisteds => "UnBlacklistedEvent" {
+            "account": u_account => hex,
+        },
+        usdc_unpauses => "UnpauseEvent" {},
+        usdc_upgradeds => "UpgradedEvent" {
+            "implementation": implementation => hex,
+        },
+    );
+}
+
+fn call_entities(calls: contract::Calls, tables: &mut Tables) {
+    call_entities!(calls, tables,
+        usdc_call_approves => "ApproveCall" {
+            "spender": spender => hex,
+            "value": value => plain,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_blacklists => "BlacklistCall" {
+            "account": u_account => hex,
+        },
+        usdc_call_burns => "BurnCall" {
+            "amount": u_amount => plain,
+        },
+        usdc_call_cancel_authorization_1s => "CancelAuthorization1Call" {
+            "authorizer": authorizer => hex,
+            "nonce": nonce => hex,
+            "v": v => int,
+            "r": r => hex,
+            "s": s => hex,
+        },
+        usdc_call_cancel_authorization_2s => "CancelAuthorization2Call" {
+            "authorizer": authorizer => hex,
+            "nonce": nonce => hex,
+            "signature": signature => hex,
+        },
+        usdc_call_change_admins => "ChangeAdminCall" {
+            "newAdmin": new_admin => hex,
+        },
+        usdc_call_configure_minters => "ConfigureMinterCall" {
+            "minter": minter => hex,
+            "minterAllowedAmount": minter_allowed_amount => plain,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_decrease_allowances => "DecreaseAllowanceCall" {
+            "spender": spender => hex,
+            "decrement": decrement => plain,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_increase_allowances => "IncreaseAllowanceCall" {
+            "spender": spender => hex,
+            "increment": increment => plain,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_initializes => "InitializeCall" {
+            "tokenName": token_name => plain,
+            "tokenSymbol": token_symbol => plain,
+            "tokenCurrency": token_currency => plain,
+            "tokenDecimals": token_decimals => int,
+            "newMasterMinter": new_master_minter => hex,
+            "newPauser": new_pauser => hex,
+            "newBlacklister": new_blacklister => hex,
+            "newOwner": new_owner => hex,
+        },
+        usdc_call_initialize_v_2s => "InitializeV2Call" {
+            "newName": new_name => plain,
+        },
+        usdc_call_initialize_v2_1s => "InitializeV21Call" {
+            "lostAndFound": lost_and_found => hex,
+        },
+        usdc_call_initialize_v2_2s => "InitializeV22Call" {
+            "accountsToBlacklist": accounts_to_blacklist => hexes,
+            "newSymbol": new_symbol => plain,
+        },
+        usdc_call_mints => "MintCall" {
+            "to": u_to => hex,
+            "amount": u_amount => plain,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_pauses => "PauseCall" {},
+        usdc_call_permit_1s => "Permit1Call" {
+            "owner": owner => hex,
+            "spender": spender => hex,
+            "value": value => plain,
+            "deadline": deadline => plain,
+            "signature": signature => hex,
+        },
+        usdc_call_permit_2s => "Permit2Call" {
+            "owner": owner => hex,
+            "spender": spender => hex,
+            "value": value => plain,
+            "deadline": deadline => plain,
+            "v": v => int,
+            "r": r => hex,
+            "s": s => hex,
+        },
+        usdc_call_receive_with_authorization_1s => "ReceiveWithAuthorization1Call" {
+            "from": from => hex,
+            "to": to => hex,
+            "value": value => plain,
+            "validAfter": valid_after => plain,
+            "validBefore": valid_before => plain,
+            "nonce": nonce => hex,
+            "signature": signature => hex,
+        },
+        usdc_call_receive_with_authorization_2s => "ReceiveWithAuthorization2Call" {
+            "from": from => hex,
+            "to": to => hex,
+            "value": value => plain,
+            "validAfter": valid_after => plain,
+            "validBefore": valid_before => plain,
+            "nonce": nonce => hex,
+            "v": v => int,
+            "r": r => hex,
+            "s": s => hex,
+        },
+        usdc_call_remove_minters => "RemoveMinterCall" {
+            "minter": minter => hex,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_rescue_erc_20s => "RescueErc20Call" {
+            "tokenContract": token_contract => hex,
+            "to": to => hex,
+            "amount": amount => plain,
+        },
+        usdc_call_transfers => "TransferCall" {
+            "to": to => hex,
+            "value": value => plain,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_transfer_froms => "TransferFromCall" {
+            "from": from => hex,
+            "to": to => hex,
+            "value": value => plain,
+            "outputParam0": output_param0 => plain,
+        },
+        usdc_call_transfer_ownerships => "TransferOwnershipCall" {
+            "newOwner": new_owner => hex,
+        },
+        usdc_call_transfer_with_authorization_1s => "TransferWithAuthorization1Call" {
+            "from": from => hex,
+            "to": to => hex,
+            "value": value => plain,
+            "validAfter": valid_after => plain,
+            "validBefore": valid_before => plain,
+            "nonce": nonce => hex,
+            "signature": signature => hex,
+        },
+        usdc_call_transfer_with_authorization_2s => "TransferWithAuthorization2Call" {
+            "from": from => hex,
+            "to": to => hex,
+            "value": value => plain,
+            "validAfter": valid_after => plain,
+            "validBefore": valid_before => plain,
+            "nonce": nonce => hex,
+            "v": v => int,
+            "r": r => hex,
+            "s": s => hex,
+        },
+        usdc_call_un_blacklists => "UnBlacklistCall" {
+            "account": u_account => hex,
+        },
+        usdc_call_unpauses => "UnpauseCall" {},
+        usdc_call_update_blacklisters => "UpdateBlacklisterCall" {
+            "newBlacklister": u_new_blacklister => hex,
+        },
+        usdc_call_update_master_minters => "UpdateMasterMinterCall" {
+            "newMasterMinter": u_new_master_minter => hex,
+        },
+        usdc_call_update_pausers => "UpdatePauserCall" {
+            "newPauser": u_new_pauser => hex,
+        },
+        usdc_call_update_rescuers => "UpdateRescuerCall" {
+            "newRescuer": new_rescuer => hex,
+        },
+        usdc_call_upgrade_tos => "UpgradeToCall" {
+            "newImplementation": new_implementation => hex,
+        },
+        usdc_call_upgrade_to_and_calls => "UpgradeToAndCallCall" {
+            "newImplementation": new_implementation => hex,
+            "data": data => hex,
+        },
+    );
+}
+
+/// Writes the current state of every account touched in the block.
+fn account_entities(
+    clock: &Clock,
+    events: &contract::Events,
+    calls: &contract::Calls,
+    stores: &AccountStores,
+    tables: &mut Tables,
+) {
+    let mut addresses = touched_addresses(events, calls);
+    addresses.extend(blacklist_changes(events));
+
+    for address in addresses {
+        let row = tables
+            .update_row("Account", hex(address.clone()))
+            .set("netChange", stores.balances.get_last(balance_key(&address)).unwrap_or_default())
+            .set("transfersSent", stores.transfers.get_last(format!("sent:{}", Hex(&address))).unwrap_or_default() as i32)
+            .set("transfersReceived", stores.transfers.get_last(format!("received:{}", Hex(&address))).unwrap_or_default() as i32)
+            .set("blacklisted", stores.blacklist.get_last(blacklist_key(&address)).unwrap_or_default() == 1)
+            .set("updatedAtBlock", clock.number as i32);
+        if let Some(block) = stores.first_seen.get_last(address_key(&address)) {
+            row.set("firstSeenBlock", block as i32);
+        }
+        if let Some(block) = stores.last_seen.get_last(address_key(&address)) {
+            row.set("lastSeenBlock", block as i32);
+        }
+    }
+}
+
+/// Writes the current state of every minter touched in the block. Whether
+/// it is a minter and its remaining allowance are read through `eth_call`,
+/// since minters configured before the initial block have no
+/// `MinterConfigured` event to derive them from.
+fn minter_entities(
+    clock: &Clock,
+    events: &contract::Events,
+    totals: &StoreGetBigInt,
+    tables: &mut Tables,
+) -> Result<(), substreams::errors::Error> {
+    let minters = touched_minters(events);
+    if minters.is_empty() {
+        return Ok(());
+    }
+
+    let responses = minters.iter()
+        .fold(RpcBatch::new(), |batch, minter| {
+            batch
+                .add(IsMinter { account: minter.clone() }, USDC_TRACKED_CONTRACT.to_vec())
+                .add(MinterAllowance { minter: minter.clone() }, USDC_TRACKED_CONTRACT.to_vec())
+        })
+        .execute()
+        .map_err(substreams::errors::Error::msg)?
+        .responses;
+
+    for (minter, responses) in minters.into_iter().zip(responses.chunks(2)) {
+        let active = RpcBatch::decode::<_, IsMinter>(&responses[0]).unwrap_or_default();
+        let allowance = RpcBatch::decode::<_, MinterAllowance>(&responses[1]).unwrap_or_default();
+
+        tables
+            .update_row("Minter", hex(minter.clone()))
+            .set("active", active)
+            .set("allowance", allowance)
+            .set("totalMinted", totals.get_last(minted_key(&minter)).unwrap_or_default())
+            .set("totalBurned", totals.get_last(burned_key(&minter)).unwrap_or_default())
+            .set("updatedAtBlock", clock.number as i32);
+    }
+
+    Ok(())
+}
+
+/// Writes the holder of every privileged role changed in the block. Events
+/// are in log order, so the last change of a role wins.
+fn role_entities(events: &contract::Events, tables: &mut Tables) {
+    let mut set_role = |role: &str, holder: &[u8], timestamp: u64, block_number: u64, tx_hash: &str| {
+        tables
+            .update_row("Role", role)
+            .set("holder", hex(holder.to_vec()))
+            .set("timestamp", timestamp as i32)
+            .set("blockNumber", block_number as i32)
+            .set("txHash", tx_hash);
+    };
+
+    for event in &events.usdc_ownership_transferreds {
+        set_role("owner", &event.new_owner, event.block_timestamp_seconds, event.evt_block_number, &event.evt_tx_hash);
+    }
+    for event in &events.usdc_admin_changeds {
+        set_role("admin", &event.new_admin, event.block_timestamp_seconds, event.evt_block_number, &event.evt_tx_hash);
+    }
+    for event in &events.usdc_pauser_changeds {
+        set_role("pauser", &event.new_address, event.block_timestamp_seconds, event.evt_block_number, &event.evt_tx_hash);
+    }
+    for event in &events.usdc_blacklister_changeds {
+        set_role("blacklister", &event.new_blacklister, event.block_timestamp_seconds, event.evt_block_number, &event.evt_tx_hash);
+    }
+    for event in &events.usdc_master_minter_changeds {
+        set_role("masterMinter", &event.new_master_minter, event.block_timestamp_seconds, event.evt_block_number, &event.evt_tx_hash);
+    }
+    for event in &events.usdc_rescuer_changeds {
+        set_role("rescuer", &event.new_rescuer, event.block_timestamp_seconds, event.evt_block_number, &event.evt_tx_hash);
+    }
+}
+
+fn daily_snapshot_entity(daily_stats: contract::DailyStats, tables: &mut Tables) {
+    if daily_stats.date.is_empty() {
+        return;
+    }
+
+    let big_int = |value: &str| substreams::scalar::BigInt::from_str(value).unwrap_or_default();
+    tables
+        .create_row("DailySnapshot", daily_stats.date)
+        .set("dayStart", seconds(&daily_stats.day_start))
+        .set("blockCount", daily_stats.block_count as i32)
+        .set("transferCount", daily_stats.transfer_count as i32)
+        .set("transferVolume", big_int(&daily_stats.transfer_volume))
+        .set("mintCount", daily_stats.mint_count as i32)
+        .set("mintVolume", big_int(&daily_stats.mint_volume))
+        .set("burnCount", daily_stats.burn_count as i32)
+        .set("burnVolume", big_int(&daily_stats.burn_volume))
+        .set("approvalCount", daily_stats.approval_count as i32)
+        .set("failedCallCount", daily_stats.failed_call_count as i32);
+}
+
+struct AccountStores {
+    balances: StoreGetBigInt,
+    first_seen: StoreGetInt64,
+    last_seen: StoreGetInt64,
+    transfers: StoreGetInt64,
+    blacklist: StoreGetInt64,
+}
+
+/// Emits the subgraph entities: one entity per event, identified by
+/// `<tx_hash>-<log_index>`, and per call, identified by
+/// `<tx_hash>-call-<ordinal>`, along with the `Account`, `Minter`, `Role` and
+/// `DailySnapshot` entities derived from the stores.
+#[substreams::handlers::map]
+fn graph_out(
+    clock: Clock,
+    events: contract::Events,
+    calls: contract::Calls,
+    daily_stats: contract::DailyStats,
+    balances: StoreGetBigInt,
+    first_seen: StoreGetInt64,
+    last_seen: StoreGetInt64,
+    transfers: StoreGetInt64,
+    blacklist: StoreGetInt64,
+    minter_totals: StoreGetBigInt,
+) -> Result<EntityChanges, substreams::errors::Error> {
+    let mut tables = Tables::new();
+    let account_stores = AccountStores { balances, first_seen, last_seen, transfers, blacklist };
+
+    account_entities(&clock, &events, &calls, &account_stores, &mut tables);
+    minter_entities(&clock, &events, &minter_totals, &mut tables)?;
+    role_entities(&events, &mut tables);
+    daily_snapshot_entity(daily_stats, &mut tables);
+    event_entities(events, &mut tables);
+    call_entities(calls, &mut tables);
+
+    Ok(tables.to_entity_changes())
+}
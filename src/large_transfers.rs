@@ -0,0 +1,57 @@
+use crate::params::Params;
+use crate::pb::contract::v1 as contract;
+use crate::ZERO_ADDRESS;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use std::str::FromStr;
+
+const USDC_DECIMALS: u64 = 6;
+
+pub fn transfer_kind(from: &[u8], to: &[u8]) -> contract::TransferKind {
+    if from == ZERO_ADDRESS {
+        contract::TransferKind::Mint
+    } else if to == ZERO_ADDRESS {
+        contract::TransferKind::Burn
+    } else {
+        contract::TransferKind::Move
+    }
+}
+
+/// Emits the `Transfer` events moving at least `min_amount` whole USDC.
+#[substreams::handlers::map]
+fn map_large_transfers(
+    params: String,
+    blk: eth::Block,
+    events: contract::Events,
+) -> Result<contract::LargeTransfers, substreams::errors::Error> {
+    let min_amount: u64 = Params::parse(&params)?.parse_or("min_amount", 1_000_000)?;
+    let threshold = BigInt::from(min_amount) * BigInt::from(10u64).pow(USDC_DECIMALS as u32);
+
+    let large_transfers = events.usdc_transfers.into_iter()
+        .filter_map(|transfer| {
+            let value = BigInt::from_str(&transfer.value).ok()?;
+            if value < threshold {
+                return None;
+            }
+
+            let tx = blk.transactions().find(|tx| Hex(&tx.hash).to_string() == transfer.evt_tx_hash);
+            Some(contract::LargeTransfer {
+                kind: transfer_kind(&transfer.from, &transfer.to) as i32,
+                amount: value.to_decimal(USDC_DECIMALS).to_string(),
+                tx_from: tx.map(|tx| tx.from.clone()).unwrap_or_default(),
+                tx_to: tx.map(|tx| tx.to.clone()).unwrap_or_default(),
+                evt_tx_hash: transfer.evt_tx_hash,
+                evt_index: transfer.evt_index,
+                evt_block_time: transfer.evt_block_time,
+                evt_block_number: transfer.evt_block_number,
+                from: transfer.from,
+                to: transfer.to,
+                value: transfer.value,
+            })
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::LargeTransfers { large_transfers })
+}
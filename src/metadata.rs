@@ -0,0 +1,105 @@
+use crate::abi::usdc_contract::functions;
+use crate::pb::contract::v1 as contract;
+use crate::USDC_TRACKED_CONTRACT;
+use substreams::pb::substreams::Clock;
+use substreams::store::{StoreGet, StoreGetInt64, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsInt64};
+use substreams_ethereum::pb::eth::rpc::RpcResponse;
+use substreams_ethereum::rpc::{RPCDecodable, RpcBatch};
+use substreams_ethereum::Function;
+
+pub const FIRST_BLOCK_KEY: &str = "first_block";
+
+fn decode<R, F: RPCDecodable<R> + Function>(responses: &[RpcResponse], index: usize) -> Option<R> {
+    responses.get(index).and_then(RpcBatch::decode::<R, F>)
+}
+
+/// Names of the events of the block that change the token metadata, plus
+/// `initialBlock` on the first block processed.
+fn triggers(clock: &Clock, events: &contract::Events, first_block: &StoreGetInt64) -> Vec<String> {
+    let mut triggers = Vec::new();
+    if first_block.get_last(FIRST_BLOCK_KEY) == Some(clock.number as i64) {
+        triggers.push("initialBlock".to_string());
+    }
+
+    let mut add = |event: &str, count: usize| {
+        if count > 0 {
+            triggers.push(event.to_string());
+        }
+    };
+    add("Upgraded", events.usdc_upgradeds.len());
+    add("AdminChanged", events.usdc_admin_changeds.len());
+    add("OwnershipTransferred", events.usdc_ownership_transferreds.len());
+    add("PauserChanged", events.usdc_pauser_changeds.len());
+    add("BlacklisterChanged", events.usdc_blacklister_changeds.len());
+    add("MasterMinterChanged", events.usdc_master_minter_changeds.len());
+    add("RescuerChanged", events.usdc_rescuer_changeds.len());
+
+    triggers
+}
+
+/// Records the first block processed, on which `map_token_metadata` takes its
+/// initial snapshot.
+#[substreams::handlers::store]
+fn store_first_block(clock: Clock, store: StoreSetIfNotExistsInt64) {
+    store.set_if_not_exists(0, FIRST_BLOCK_KEY, &(clock.number as i64));
+}
+
+/// Snapshots the token metadata and privileged roles through `eth_call`, on
+/// the first block processed and whenever the proxy is upgraded or a role
+/// changes. The proxy only answers `implementation()` and `admin()` to its
+/// admin, so when those calls revert the values come from the block's
+/// `Upgraded` and `AdminChanged` events, if any.
+#[substreams::handlers::map]
+fn map_token_metadata(
+    clock: Clock,
+    events: contract::Events,
+    first_block: StoreGetInt64,
+) -> Result<contract::TokenMetadata, substreams::errors::Error> {
+    let triggers = triggers(&clock, &events, &first_block);
+    if triggers.is_empty() {
+        substreams::skip_empty_output();
+        return Ok(contract::TokenMetadata::default());
+    }
+
+    let token = || USDC_TRACKED_CONTRACT.to_vec();
+    let responses = RpcBatch::new()
+        .add(functions::Name {}, token())
+        .add(functions::Symbol {}, token())
+        .add(functions::Decimals {}, token())
+        .add(functions::Currency {}, token())
+        .add(functions::Version {}, token())
+        .add(functions::TotalSupply {}, token())
+        .add(functions::Implementation {}, token())
+        .add(functions::Admin {}, token())
+        .add(functions::Owner {}, token())
+        .add(functions::Pauser {}, token())
+        .add(functions::Blacklister {}, token())
+        .add(functions::MasterMinter {}, token())
+        .add(functions::Rescuer {}, token())
+        .execute()
+        .map_err(substreams::errors::Error::msg)?
+        .responses;
+
+    Ok(contract::TokenMetadata {
+        block_number: clock.number,
+        block_time: clock.timestamp,
+        triggers,
+        name: decode::<_, functions::Name>(&responses, 0).unwrap_or_default(),
+        symbol: decode::<_, functions::Symbol>(&responses, 1).unwrap_or_default(),
+        decimals: decode::<_, functions::Decimals>(&responses, 2).map(|decimals| decimals.to_u64()).unwrap_or_default(),
+        currency: decode::<_, functions::Currency>(&responses, 3).unwrap_or_default(),
+        version: decode::<_, functions::Version>(&responses, 4).unwrap_or_default(),
+        total_supply: decode::<_, functions::TotalSupply>(&responses, 5).map(|supply| supply.to_string()).unwrap_or_default(),
+        implementation: decode::<_, functions::Implementation>(&responses, 6)
+            .or_else(|| events.usdc_upgradeds.last().map(|event| event.implementation.clone()))
+            .unwrap_or_default(),
+        admin: decode::<_, functions::Admin>(&responses, 7)
+            .or_else(|| events.usdc_admin_changeds.last().map(|event| event.new_admin.clone()))
+            .unwrap_or_default(),
+        owner: decode::<_, functions::Owner>(&responses, 8).unwrap_or_default(),
+        pauser: decode::<_, functions::Pauser>(&responses, 9).unwrap_or_default(),
+        blacklister: decode::<_, functions::Blacklister>(&responses, 10).unwrap_or_default(),
+        master_minter: decode::<_, functions::MasterMinter>(&responses, 11).unwrap_or_default(),
+        rescuer: decode::<_, functions::Rescuer>(&responses, 12).unwrap_or_default(),
+    })
+}
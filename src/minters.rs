@@ -0,0 +1,37 @@
+use crate::pb::contract::v1 as contract;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreNew};
+use substreams::Hex;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+pub fn minted_key(minter: &[u8]) -> String {
+    format!("minted:{}", Hex(minter))
+}
+
+pub fn burned_key(burner: &[u8]) -> String {
+    format!("burned:{}", Hex(burner))
+}
+
+/// Every minter configured, removed, minting or burning in the block.
+pub fn touched_minters(events: &contract::Events) -> BTreeSet<Vec<u8>> {
+    let mut minters = BTreeSet::new();
+    minters.extend(events.usdc_minter_configureds.iter().map(|event| event.minter.clone()));
+    minters.extend(events.usdc_minter_removeds.iter().map(|event| event.old_minter.clone()));
+    minters.extend(events.usdc_mints.iter().map(|event| event.minter.clone()));
+    minters.extend(events.usdc_burns.iter().map(|event| event.burner.clone()));
+
+    minters
+}
+
+/// Sums the amounts minted (`minted:` keys) and burned (`burned:` keys) by
+/// every minter.
+#[substreams::handlers::store]
+fn store_minter_totals(events: contract::Events, store: StoreAddBigInt) {
+    for mint in events.usdc_mints {
+        store.add(mint.evt_ordinal, minted_key(&mint.minter), BigInt::from_str(&mint.amount).unwrap_or_default());
+    }
+    for burn in events.usdc_burns {
+        store.add(burn.evt_ordinal, burned_key(&burn.burner), BigInt::from_str(&burn.amount).unwrap_or_default());
+    }
+}
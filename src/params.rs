@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Context};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Module params, given as `key=value` pairs separated by `;`, for example
+/// `fresh_spender_blocks=7200;drain_window_blocks=50`.
+pub struct Params<'a> {
+    values: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> Params<'a> {
+    pub fn parse(params: &'a str) -> Result<Self, substreams::errors::Error> {
+        let mut values = HashMap::new();
+        for pair in params.split(';').map(str::trim).filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid param {:?}, expected key=value", pair))?;
+            values.insert(key.trim(), value.trim());
+        }
+
+        Ok(Params { values })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.values.get(key).copied()
+    }
+
+    pub fn parse_or<T>(&self, key: &str, default: T) -> Result<T, substreams::errors::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| anyhow!("{}", e))
+                .with_context(|| format!("invalid value {:?} for param {}", value, key)),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Names picked through a comma-separated param, such as `events=Transfer,Mint`.
+/// Names are matched case-insensitively, and a missing param selects every name.
+pub struct Selection<'a> {
+    names: Option<Vec<&'a str>>,
+}
+
+impl<'a> Selection<'a> {
+    pub fn parse(params: &Params<'a>, key: &str, known: &[&str]) -> Result<Self, substreams::errors::Error> {
+        let names = match params.get(key) {
+            Some(value) => value.split(',').map(str::trim).filter(|name| !name.is_empty()).collect::<Vec<_>>(),
+            None => return Ok(Selection { names: None }),
+        };
+
+        if let Some(unknown) = names.iter().find(|name| !known.iter().any(|k| k.eq_ignore_ascii_case(name))) {
+            return Err(anyhow!("unknown name {:?} in param {}, expected one of {}", unknown, key, known.join(",")));
+        }
+
+        Ok(Selection { names: Some(names) })
+    }
+
+    pub fn includes(&self, name: &str) -> bool {
+        match &self.names {
+            Some(names) => names.iter().any(|n| n.eq_ignore_ascii_case(name)),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Params, Selection};
+
+    const KNOWN: [&str; 3] = ["Transfer", "Approval", "Mint"];
+
+    #[test]
+    fn parse_pairs() {
+        let params = Params::parse(" events = Transfer,Mint ; min_amount=10;").unwrap();
+        assert_eq!(params.get("events"), Some("Transfer,Mint"));
+        assert_eq!(params.parse_or("min_amount", 0u64).unwrap(), 10);
+        assert_eq!(params.parse_or("max_checks", 20usize).unwrap(), 20);
+        assert_eq!(params.get("calls"), None);
+    }
+
+    #[test]
+    fn parse_empty() {
+        let params = Params::parse("").unwrap();
+        assert_eq!(params.get("events"), None);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Params::parse("events").is_err());
+        assert!(Params::parse("min_amount=ten").unwrap().parse_or("min_amount", 0u64).is_err());
+    }
+
+    #[test]
+    fn selection_missing_selects_everything() {
+        let params = Params::parse("calls=transfer").unwrap();
+        let selection = Selection::parse(&params, "events", &KNOWN).unwrap();
+        assert!(KNOWN.iter().all(|name| selection.includes(name)));
+    }
+
+    #[test]
+    fn selection_empty_selects_nothing() {
+        for value in ["events=", "events= , "] {
+            let params = Params::parse(value).unwrap();
+            let selection = Selection::parse(&params, "events", &KNOWN).unwrap();
+            assert!(KNOWN.iter().all(|name| !selection.includes(name)), "{}", value);
+        }
+    }
+
+    #[test]
+    fn selection_matches_case_insensitively() {
+        let params = Params::parse("events=transfer, MINT").unwrap();
+        let selection = Selection::parse(&params, "events", &KNOWN).unwrap();
+        assert!(selection.includes("Transfer"));
+        assert!(selection.includes("Mint"));
+        assert!(!selection.includes("Approval"));
+    }
+
+    #[test]
+    fn selection_rejects_unknown_names() {
+        let params = Params::parse("events=Transfer,Swap").unwrap();
+        let error = Selection::parse(&params, "events", &KNOWN).err().unwrap();
+        assert!(error.to_string().contains("\"Swap\""), "{}", error);
+    }
+}
@@ -136,6 +136,14 @@ pub struct UsdcAdminChanged {
     pub previous_admin: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
     pub new_admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -154,6 +162,14 @@ pub struct UsdcApproval {
     pub spender: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="7")]
     pub value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="11")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -170,6 +186,14 @@ pub struct UsdcAuthorizationCanceled {
     pub authorizer: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
     pub nonce: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -186,6 +210,14 @@ pub struct UsdcAuthorizationUsed {
     pub authorizer: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
     pub nonce: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -200,6 +232,14 @@ pub struct UsdcBlacklisted {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub u_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -214,6 +254,14 @@ pub struct UsdcBlacklisterChanged {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub new_blacklister: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -230,6 +278,14 @@ pub struct UsdcBurn {
     pub burner: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="6")]
     pub amount: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -244,6 +300,14 @@ pub struct UsdcMasterMinterChanged {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub new_master_minter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -262,6 +326,14 @@ pub struct UsdcMint {
     pub to: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="7")]
     pub amount: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="11")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -278,6 +350,14 @@ pub struct UsdcMinterConfigured {
     pub minter: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="6")]
     pub minter_allowed_amount: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -292,6 +372,14 @@ pub struct UsdcMinterRemoved {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub old_minter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -308,6 +396,14 @@ pub struct UsdcOwnershipTransferred {
     pub previous_owner: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
     pub new_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -320,6 +416,14 @@ pub struct UsdcPause {
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="6")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="8")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -334,6 +438,14 @@ pub struct UsdcPauserChanged {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub new_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -348,6 +460,14 @@ pub struct UsdcRescuerChanged {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub new_rescuer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -366,6 +486,14 @@ pub struct UsdcTransfer {
     pub to: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="7")]
     pub value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="11")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -380,6 +508,14 @@ pub struct UsdcUnBlacklisted {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub u_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -392,6 +528,14 @@ pub struct UsdcUnpause {
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="6")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="8")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -406,6 +550,14 @@ pub struct UsdcUpgraded {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub implementation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="7")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_timestamp_seconds: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub evt_address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -426,6 +578,14 @@ pub struct UsdcApproveCall {
     pub value: ::prost::alloc::string::String,
     #[prost(bool, tag="8")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -442,6 +602,14 @@ pub struct UsdcBlacklistCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub u_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -458,6 +626,14 @@ pub struct UsdcBurnCall {
     pub call_success: bool,
     #[prost(string, tag="6")]
     pub u_amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -482,6 +658,14 @@ pub struct UsdcCancelAuthorization1call {
     pub r: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="10")]
     pub s: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="12")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="14")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -502,6 +686,14 @@ pub struct UsdcCancelAuthorization2call {
     pub nonce: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="8")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -518,6 +710,14 @@ pub struct UsdcChangeAdminCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub new_admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -538,6 +738,14 @@ pub struct UsdcConfigureMinterCall {
     pub minter_allowed_amount: ::prost::alloc::string::String,
     #[prost(bool, tag="8")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -558,6 +766,14 @@ pub struct UsdcDecreaseAllowanceCall {
     pub decrement: ::prost::alloc::string::String,
     #[prost(bool, tag="8")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -578,6 +794,14 @@ pub struct UsdcIncreaseAllowanceCall {
     pub increment: ::prost::alloc::string::String,
     #[prost(bool, tag="8")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -608,6 +832,14 @@ pub struct UsdcInitializeCall {
     pub new_blacklister: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="13")]
     pub new_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="14")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="15")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="16")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="17")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -624,6 +856,14 @@ pub struct UsdcInitializeV2call {
     pub call_success: bool,
     #[prost(string, tag="6")]
     pub new_name: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -640,6 +880,14 @@ pub struct UsdcInitializeV21call {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub lost_and_found: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -658,6 +906,14 @@ pub struct UsdcInitializeV22call {
     pub accounts_to_blacklist: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     #[prost(string, tag="7")]
     pub new_symbol: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -678,6 +934,14 @@ pub struct UsdcMintCall {
     pub u_amount: ::prost::alloc::string::String,
     #[prost(bool, tag="8")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -692,6 +956,14 @@ pub struct UsdcPauseCall {
     pub call_ordinal: u64,
     #[prost(bool, tag="5")]
     pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -716,6 +988,14 @@ pub struct UsdcPermit1call {
     pub deadline: ::prost::alloc::string::String,
     #[prost(bytes="vec", tag="10")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="12")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="14")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -744,6 +1024,14 @@ pub struct UsdcPermit2call {
     pub r: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="12")]
     pub s: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="14")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="15")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="16")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -772,6 +1060,14 @@ pub struct UsdcReceiveWithAuthorization1call {
     pub nonce: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="12")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="14")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="15")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="16")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -804,6 +1100,14 @@ pub struct UsdcReceiveWithAuthorization2call {
     pub r: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="14")]
     pub s: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="15")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="16")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="17")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="18")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -822,6 +1126,14 @@ pub struct UsdcRemoveMinterCall {
     pub minter: ::prost::alloc::vec::Vec<u8>,
     #[prost(bool, tag="7")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="8")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -842,6 +1154,14 @@ pub struct UsdcRescueErc20call {
     pub to: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="8")]
     pub amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -862,6 +1182,14 @@ pub struct UsdcTransferCall {
     pub value: ::prost::alloc::string::String,
     #[prost(bool, tag="8")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="9")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -884,6 +1212,14 @@ pub struct UsdcTransferFromCall {
     pub value: ::prost::alloc::string::String,
     #[prost(bool, tag="9")]
     pub output_param0: bool,
+    #[prost(bytes="vec", tag="10")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="11")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="13")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -900,6 +1236,14 @@ pub struct UsdcTransferOwnershipCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub new_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -928,6 +1272,14 @@ pub struct UsdcTransferWithAuthorization1call {
     pub nonce: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="12")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="14")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="15")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="16")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -960,6 +1312,14 @@ pub struct UsdcTransferWithAuthorization2call {
     pub r: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="14")]
     pub s: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="15")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="16")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="17")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="18")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -976,6 +1336,14 @@ pub struct UsdcUnBlacklistCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub u_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -990,6 +1358,14 @@ pub struct UsdcUnpauseCall {
     pub call_ordinal: u64,
     #[prost(bool, tag="5")]
     pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1006,6 +1382,14 @@ pub struct UsdcUpdateBlacklisterCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub u_new_blacklister: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1022,6 +1406,14 @@ pub struct UsdcUpdateMasterMinterCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub u_new_master_minter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1038,6 +1430,14 @@ pub struct UsdcUpdatePauserCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub u_new_pauser: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1054,6 +1454,14 @@ pub struct UsdcUpdateRescuerCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub new_rescuer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1070,6 +1478,14 @@ pub struct UsdcUpgradeToCall {
     pub call_success: bool,
     #[prost(bytes="vec", tag="6")]
     pub new_implementation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub block_timestamp_seconds: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1088,5 +1504,902 @@ pub struct UsdcUpgradeToAndCallCall {
     pub new_implementation: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="7")]
     pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub call_end_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub block_timestamp_seconds: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Permit {
+    #[prost(string, tag="1")]
+    pub permit_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub permit_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub permit_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub permit_ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub deadline: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermitSpends {
+    #[prost(message, repeated, tag="1")]
+    pub permit_spends: ::prost::alloc::vec::Vec<PermitSpend>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermitSpend {
+    #[prost(string, tag="1")]
+    pub permit_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub permit_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub permit_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub permit_ordinal: u64,
+    #[prost(string, tag="5")]
+    pub spend_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="6")]
+    pub spend_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="7")]
+    pub spend_block_number: u64,
+    #[prost(uint64, tag="8")]
+    pub spend_ordinal: u64,
+    #[prost(bytes="vec", tag="9")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="12")]
+    pub permit_value: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub spend_value: ::prost::alloc::string::String,
+    #[prost(string, tag="14")]
+    pub deadline: ::prost::alloc::string::String,
+    #[prost(bool, tag="15")]
+    pub same_tx: bool,
+    #[prost(uint64, tag="16")]
+    pub seconds_since_permit: u64,
+    #[prost(uint64, tag="17")]
+    pub blocks_since_permit: u64,
+    #[prost(string, tag="18")]
+    pub remaining_value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuspiciousActivities {
+    #[prost(message, repeated, tag="1")]
+    pub suspicious_activities: ::prost::alloc::vec::Vec<SuspiciousActivity>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuspiciousActivity {
+    #[prost(enumeration="SuspicionKind", tag="1")]
+    pub kind: i32,
+    #[prost(string, tag="2")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub spender_first_seen_block: u64,
+    #[prost(uint64, tag="9")]
+    pub blocks_since_permit: u64,
+    #[prost(string, tag="10")]
+    pub remaining_balance: ::prost::alloc::string::String,
+    #[prost(int64, tag="11")]
+    pub approver_count: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LargeTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub large_transfers: ::prost::alloc::vec::Vec<LargeTransfer>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LargeTransfer {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(enumeration="TransferKind", tag="9")]
+    pub kind: i32,
+    #[prost(bytes="vec", tag="10")]
+    pub tx_from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub tx_to: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FunctionFailures {
+    #[prost(string, tag="1")]
+    pub function: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockStats {
+    #[prost(uint64, tag="1")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub transfer_count: u64,
+    #[prost(string, tag="4")]
+    pub transfer_volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub unique_senders: u64,
+    #[prost(uint64, tag="6")]
+    pub unique_receivers: u64,
+    #[prost(uint64, tag="7")]
+    pub mint_count: u64,
+    #[prost(string, tag="8")]
+    pub mint_volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub burn_count: u64,
+    #[prost(string, tag="10")]
+    pub burn_volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub approval_count: u64,
+    #[prost(message, repeated, tag="12")]
+    pub failed_calls: ::prost::alloc::vec::Vec<FunctionFailures>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DailyStats {
+    #[prost(string, tag="1")]
+    pub date: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub day_start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_count: u64,
+    #[prost(uint64, tag="4")]
+    pub transfer_count: u64,
+    #[prost(string, tag="5")]
+    pub transfer_volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub mint_count: u64,
+    #[prost(string, tag="7")]
+    pub mint_volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub burn_count: u64,
+    #[prost(string, tag="9")]
+    pub burn_volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub approval_count: u64,
+    #[prost(uint64, tag="11")]
+    pub failed_call_count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressActivities {
+    #[prost(message, repeated, tag="1")]
+    pub address_activities: ::prost::alloc::vec::Vec<AddressActivity>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressActivity {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub first_seen_block: u64,
+    #[prost(uint64, tag="3")]
+    pub last_seen_block: u64,
+    #[prost(uint64, tag="4")]
+    pub transfers_sent: u64,
+    #[prost(uint64, tag="5")]
+    pub transfers_received: u64,
+    #[prost(bool, tag="6")]
+    pub new_address: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Activities {
+    #[prost(message, repeated, tag="1")]
+    pub activities: ::prost::alloc::vec::Vec<Activity>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Activity {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub ordinal: u64,
+    #[prost(string, tag="3")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="5")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(oneof="activity::Payload", tags="10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73")]
+    pub payload: ::core::option::Option<activity::Payload>,
+}
+/// Nested message and enum types in `Activity`.
+pub mod activity {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Payload {
+        #[prost(message, tag="10")]
+        UsdcAdminChanged(super::UsdcAdminChanged),
+        #[prost(message, tag="11")]
+        UsdcApproval(super::UsdcApproval),
+        #[prost(message, tag="12")]
+        UsdcAuthorizationCanceled(super::UsdcAuthorizationCanceled),
+        #[prost(message, tag="13")]
+        UsdcAuthorizationUsed(super::UsdcAuthorizationUsed),
+        #[prost(message, tag="14")]
+        UsdcBlacklisted(super::UsdcBlacklisted),
+        #[prost(message, tag="15")]
+        UsdcBlacklisterChanged(super::UsdcBlacklisterChanged),
+        #[prost(message, tag="16")]
+        UsdcBurn(super::UsdcBurn),
+        #[prost(message, tag="17")]
+        UsdcMasterMinterChanged(super::UsdcMasterMinterChanged),
+        #[prost(message, tag="18")]
+        UsdcMint(super::UsdcMint),
+        #[prost(message, tag="19")]
+        UsdcMinterConfigured(super::UsdcMinterConfigured),
+        #[prost(message, tag="20")]
+        UsdcMinterRemoved(super::UsdcMinterRemoved),
+        #[prost(message, tag="21")]
+        UsdcOwnershipTransferred(super::UsdcOwnershipTransferred),
+        #[prost(message, tag="22")]
+        UsdcPause(super::UsdcPause),
+        #[prost(message, tag="23")]
+        UsdcPauserChanged(super::UsdcPauserChanged),
+        #[prost(message, tag="24")]
+        UsdcRescuerChanged(super::UsdcRescuerChanged),
+        #[prost(message, tag="25")]
+        UsdcTransfer(super::UsdcTransfer),
+        #[prost(message, tag="26")]
+        UsdcUnBlacklisted(super::UsdcUnBlacklisted),
+        #[prost(message, tag="27")]
+        UsdcUnpause(super::UsdcUnpause),
+        #[prost(message, tag="28")]
+        UsdcUpgraded(super::UsdcUpgraded),
+        #[prost(message, tag="40")]
+        UsdcCallApprove(super::UsdcApproveCall),
+        #[prost(message, tag="41")]
+        UsdcCallBlacklist(super::UsdcBlacklistCall),
+        #[prost(message, tag="42")]
+        UsdcCallBurn(super::UsdcBurnCall),
+        #[prost(message, tag="43")]
+        UsdcCallCancelAuthorization1(super::UsdcCancelAuthorization1call),
+        #[prost(message, tag="44")]
+        UsdcCallCancelAuthorization2(super::UsdcCancelAuthorization2call),
+        #[prost(message, tag="45")]
+        UsdcCallChangeAdmin(super::UsdcChangeAdminCall),
+        #[prost(message, tag="46")]
+        UsdcCallConfigureMinter(super::UsdcConfigureMinterCall),
+        #[prost(message, tag="47")]
+        UsdcCallDecreaseAllowance(super::UsdcDecreaseAllowanceCall),
+        #[prost(message, tag="48")]
+        UsdcCallIncreaseAllowance(super::UsdcIncreaseAllowanceCall),
+        #[prost(message, tag="49")]
+        UsdcCallInitialize(super::UsdcInitializeCall),
+        #[prost(message, tag="50")]
+        UsdcCallInitializeV2(super::UsdcInitializeV2call),
+        #[prost(message, tag="51")]
+        UsdcCallInitializeV21(super::UsdcInitializeV21call),
+        #[prost(message, tag="52")]
+        UsdcCallInitializeV22(super::UsdcInitializeV22call),
+        #[prost(message, tag="53")]
+        UsdcCallMint(super::UsdcMintCall),
+        #[prost(message, tag="54")]
+        UsdcCallPause(super::UsdcPauseCall),
+        #[prost(message, tag="55")]
+        UsdcCallPermit1(super::UsdcPermit1call),
+        #[prost(message, tag="56")]
+        UsdcCallPermit2(super::UsdcPermit2call),
+        #[prost(message, tag="57")]
+        UsdcCallReceiveWithAuthorization1(super::UsdcReceiveWithAuthorization1call),
+        #[prost(message, tag="58")]
+        UsdcCallReceiveWithAuthorization2(super::UsdcReceiveWithAuthorization2call),
+        #[prost(message, tag="59")]
+        UsdcCallRemoveMinter(super::UsdcRemoveMinterCall),
+        #[prost(message, tag="60")]
+        UsdcCallRescueErc20(super::UsdcRescueErc20call),
+        #[prost(message, tag="61")]
+        UsdcCallTransfer(super::UsdcTransferCall),
+        #[prost(message, tag="62")]
+        UsdcCallTransferFrom(super::UsdcTransferFromCall),
+        #[prost(message, tag="63")]
+        UsdcCallTransferOwnership(super::UsdcTransferOwnershipCall),
+        #[prost(message, tag="64")]
+        UsdcCallTransferWithAuthorization1(super::UsdcTransferWithAuthorization1call),
+        #[prost(message, tag="65")]
+        UsdcCallTransferWithAuthorization2(super::UsdcTransferWithAuthorization2call),
+        #[prost(message, tag="66")]
+        UsdcCallUnBlacklist(super::UsdcUnBlacklistCall),
+        #[prost(message, tag="67")]
+        UsdcCallUnpause(super::UsdcUnpauseCall),
+        #[prost(message, tag="68")]
+        UsdcCallUpdateBlacklister(super::UsdcUpdateBlacklisterCall),
+        #[prost(message, tag="69")]
+        UsdcCallUpdateMasterMinter(super::UsdcUpdateMasterMinterCall),
+        #[prost(message, tag="70")]
+        UsdcCallUpdatePauser(super::UsdcUpdatePauserCall),
+        #[prost(message, tag="71")]
+        UsdcCallUpdateRescuer(super::UsdcUpdateRescuerCall),
+        #[prost(message, tag="72")]
+        UsdcCallUpgradeTo(super::UsdcUpgradeToCall),
+        #[prost(message, tag="73")]
+        UsdcCallUpgradeToAndCall(super::UsdcUpgradeToAndCallCall),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnrichedTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub enriched_transfers: ::prost::alloc::vec::Vec<EnrichedTransfer>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnrichedTransfer {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub evt_ordinal: u64,
+    #[prost(bytes="vec", tag="6")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(enumeration="TransferKind", tag="9")]
+    pub kind: i32,
+    #[prost(string, tag="10")]
+    pub entry_point: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub entry_point_ordinal: u64,
+    #[prost(bytes="vec", tag="12")]
+    pub entry_point_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="13")]
+    pub emitting_function: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceObservations {
+    #[prost(message, repeated, tag="1")]
+    pub balance_observations: ::prost::alloc::vec::Vec<BalanceObservation>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceObservation {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag="4")]
+    pub observed_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub tracked_change: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceMismatches {
+    #[prost(message, repeated, tag="1")]
+    pub balance_mismatches: ::prost::alloc::vec::Vec<BalanceMismatch>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceMismatch {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag="4")]
+    pub observed_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub expected_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub difference: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenMetadata {
+    #[prost(uint64, tag="1")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, repeated, tag="3")]
+    pub triggers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub decimals: u64,
+    #[prost(string, tag="7")]
+    pub currency: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub version: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub total_supply: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="10")]
+    pub implementation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="12")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub pauser: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="14")]
+    pub blacklister: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="15")]
+    pub master_minter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="16")]
+    pub rescuer: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizationUse {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub authorizer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub submitter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub function: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizationConflicts {
+    #[prost(message, repeated, tag="1")]
+    pub authorization_conflicts: ::prost::alloc::vec::Vec<AuthorizationConflict>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizationConflict {
+    #[prost(enumeration="ConflictKind", tag="1")]
+    pub kind: i32,
+    #[prost(string, tag="2")]
+    pub function: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="4")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="5")]
+    pub block_number: u64,
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    #[prost(bool, tag="7")]
+    pub success: bool,
+    #[prost(bytes="vec", tag="8")]
+    pub authorizer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub submitter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub payee: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="12")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub used_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="14")]
+    pub used_block_number: u64,
+    #[prost(bytes="vec", tag="15")]
+    pub used_submitter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="16")]
+    pub used_function: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedCalls {
+    #[prost(message, repeated, tag="1")]
+    pub failed_calls: ::prost::alloc::vec::Vec<FailedCall>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedCall {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(string, tag="5")]
+    pub function: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="RevertKind", tag="7")]
+    pub kind: i32,
+    #[prost(string, tag="8")]
+    pub reason: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="9")]
+    pub return_data: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradeRecords {
+    #[prost(message, repeated, tag="1")]
+    pub upgrade_records: ::prost::alloc::vec::Vec<UpgradeRecord>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradeRecord {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(string, tag="5")]
+    pub function: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub implementation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub call_data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="9")]
+    pub initializer: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub new_name: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="11")]
+    pub lost_and_found: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="12")]
+    pub accounts_to_blacklist: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, tag="13")]
+    pub new_symbol: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="14")]
+    pub code_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="15")]
+    pub code_size: u64,
+    #[prost(bytes="vec", tag="16")]
+    pub previous_implementation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="17")]
+    pub previous_code_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="18")]
+    pub previous_code_size: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeployedCode {
+    #[prost(bytes="vec", tag="1")]
+    pub code_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub code_size: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateChanges {
+    #[prost(message, repeated, tag="1")]
+    pub balance_changes: ::prost::alloc::vec::Vec<BalanceChange>,
+    #[prost(message, repeated, tag="2")]
+    pub allowance_changes: ::prost::alloc::vec::Vec<AllowanceChange>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceChange {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub old: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub new: ::prost::alloc::string::String,
+    #[prost(bool, tag="8")]
+    pub blacklisted: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AllowanceChange {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub old: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub new: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionFees {
+    #[prost(message, repeated, tag="1")]
+    pub transaction_fees: ::prost::alloc::vec::Vec<TransactionFee>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionFee {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(bytes="vec", tag="4")]
+    pub fee_payer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub gas_used: u64,
+    #[prost(string, tag="6")]
+    pub effective_gas_price: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub base_fee_per_gas: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub priority_fee_per_gas: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub total_fee: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub base_fee: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub priority_fee: ::prost::alloc::string::String,
+    #[prost(bytes="vec", repeated, tag="12")]
+    pub usdc_senders: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bool, tag="13")]
+    pub sponsored: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiscoveredContracts {
+    #[prost(message, repeated, tag="1")]
+    pub discovered_contracts: ::prost::alloc::vec::Vec<DiscoveredContract>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiscoveredContract {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="3")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="4")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="5")]
+    pub block_number: u64,
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchList {
+    #[prost(bytes="vec", repeated, tag="1")]
+    pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SuspicionKind {
+    Unspecified = 0,
+    UnlimitedApprovalToFreshSpender = 1,
+    PermitDrain = 2,
+    SpenderApprovalSurge = 3,
+}
+impl SuspicionKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SuspicionKind::Unspecified => "SUSPICION_KIND_UNSPECIFIED",
+            SuspicionKind::UnlimitedApprovalToFreshSpender => "SUSPICION_KIND_UNLIMITED_APPROVAL_TO_FRESH_SPENDER",
+            SuspicionKind::PermitDrain => "SUSPICION_KIND_PERMIT_DRAIN",
+            SuspicionKind::SpenderApprovalSurge => "SUSPICION_KIND_SPENDER_APPROVAL_SURGE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SUSPICION_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "SUSPICION_KIND_UNLIMITED_APPROVAL_TO_FRESH_SPENDER" => Some(Self::UnlimitedApprovalToFreshSpender),
+            "SUSPICION_KIND_PERMIT_DRAIN" => Some(Self::PermitDrain),
+            "SUSPICION_KIND_SPENDER_APPROVAL_SURGE" => Some(Self::SpenderApprovalSurge),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TransferKind {
+    Unspecified = 0,
+    Move = 1,
+    Mint = 2,
+    Burn = 3,
+}
+impl TransferKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TransferKind::Unspecified => "TRANSFER_KIND_UNSPECIFIED",
+            TransferKind::Move => "TRANSFER_KIND_MOVE",
+            TransferKind::Mint => "TRANSFER_KIND_MINT",
+            TransferKind::Burn => "TRANSFER_KIND_BURN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TRANSFER_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "TRANSFER_KIND_MOVE" => Some(Self::Move),
+            "TRANSFER_KIND_MINT" => Some(Self::Mint),
+            "TRANSFER_KIND_BURN" => Some(Self::Burn),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ConflictKind {
+    Unspecified = 0,
+    Replay = 1,
+    FrontRun = 2,
+    NonPayeeReceive = 3,
+}
+impl ConflictKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ConflictKind::Unspecified => "CONFLICT_KIND_UNSPECIFIED",
+            ConflictKind::Replay => "CONFLICT_KIND_REPLAY",
+            ConflictKind::FrontRun => "CONFLICT_KIND_FRONT_RUN",
+            ConflictKind::NonPayeeReceive => "CONFLICT_KIND_NON_PAYEE_RECEIVE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CONFLICT_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "CONFLICT_KIND_REPLAY" => Some(Self::Replay),
+            "CONFLICT_KIND_FRONT_RUN" => Some(Self::FrontRun),
+            "CONFLICT_KIND_NON_PAYEE_RECEIVE" => Some(Self::NonPayeeReceive),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RevertKind {
+    Unspecified = 0,
+    ParentReverted = 1,
+    NoData = 2,
+    Panic = 3,
+    Other = 4,
+    Blacklisted = 5,
+    Paused = 6,
+    InsufficientBalance = 7,
+    InsufficientAllowance = 8,
+    ZeroAddress = 9,
+    InvalidAmount = 10,
+    InvalidSignature = 11,
+    Expired = 12,
+    AuthorizationNotYetValid = 13,
+    AuthorizationUsed = 14,
+    CallerNotPayee = 15,
+    UnauthorizedCaller = 16,
+    MintAllowanceExceeded = 17,
+}
+impl RevertKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RevertKind::Unspecified => "REVERT_KIND_UNSPECIFIED",
+            RevertKind::ParentReverted => "REVERT_KIND_PARENT_REVERTED",
+            RevertKind::NoData => "REVERT_KIND_NO_DATA",
+            RevertKind::Panic => "REVERT_KIND_PANIC",
+            RevertKind::Other => "REVERT_KIND_OTHER",
+            RevertKind::Blacklisted => "REVERT_KIND_BLACKLISTED",
+            RevertKind::Paused => "REVERT_KIND_PAUSED",
+            RevertKind::InsufficientBalance => "REVERT_KIND_INSUFFICIENT_BALANCE",
+            RevertKind::InsufficientAllowance => "REVERT_KIND_INSUFFICIENT_ALLOWANCE",
+            RevertKind::ZeroAddress => "REVERT_KIND_ZERO_ADDRESS",
+            RevertKind::InvalidAmount => "REVERT_KIND_INVALID_AMOUNT",
+            RevertKind::InvalidSignature => "REVERT_KIND_INVALID_SIGNATURE",
+            RevertKind::Expired => "REVERT_KIND_EXPIRED",
+            RevertKind::AuthorizationNotYetValid => "REVERT_KIND_AUTHORIZATION_NOT_YET_VALID",
+            RevertKind::AuthorizationUsed => "REVERT_KIND_AUTHORIZATION_USED",
+            RevertKind::CallerNotPayee => "REVERT_KIND_CALLER_NOT_PAYEE",
+            RevertKind::UnauthorizedCaller => "REVERT_KIND_UNAUTHORIZED_CALLER",
+            RevertKind::MintAllowanceExceeded => "REVERT_KIND_MINT_ALLOWANCE_EXCEEDED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REVERT_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "REVERT_KIND_PARENT_REVERTED" => Some(Self::ParentReverted),
+            "REVERT_KIND_NO_DATA" => Some(Self::NoData),
+            "REVERT_KIND_PANIC" => Some(Self::Panic),
+            "REVERT_KIND_OTHER" => Some(Self::Other),
+            "REVERT_KIND_BLACKLISTED" => Some(Self::Blacklisted),
+            "REVERT_KIND_PAUSED" => Some(Self::Paused),
+            "REVERT_KIND_INSUFFICIENT_BALANCE" => Some(Self::InsufficientBalance),
+            "REVERT_KIND_INSUFFICIENT_ALLOWANCE" => Some(Self::InsufficientAllowance),
+            "REVERT_KIND_ZERO_ADDRESS" => Some(Self::ZeroAddress),
+            "REVERT_KIND_INVALID_AMOUNT" => Some(Self::InvalidAmount),
+            "REVERT_KIND_INVALID_SIGNATURE" => Some(Self::InvalidSignature),
+            "REVERT_KIND_EXPIRED" => Some(Self::Expired),
+            "REVERT_KIND_AUTHORIZATION_NOT_YET_VALID" => Some(Self::AuthorizationNotYetValid),
+            "REVERT_KIND_AUTHORIZATION_USED" => Some(Self::AuthorizationUsed),
+            "REVERT_KIND_CALLER_NOT_PAYEE" => Some(Self::CallerNotPayee),
+            "REVERT_KIND_UNAUTHORIZED_CALLER" => Some(Self::UnauthorizedCaller),
+            "REVERT_KIND_MINT_ALLOWANCE_EXCEEDED" => Some(Self::MintAllowanceExceeded),
+            _ => None,
+        }
+    }
 }
 // @@protoc_insertion_point(module)
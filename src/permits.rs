@@ -0,0 +1,192 @@
+use crate::pb::contract::v1 as contract;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    StoreAdd, StoreAddBigInt, StoreDelete, StoreGet, StoreGetBigInt, StoreGetProto, StoreNew, StoreSet, StoreSetProto,
+};
+use substreams::Hex;
+use std::str::FromStr;
+
+/// `type(uint256).max`, the allowance that `transferFrom` never decreases.
+pub const UNLIMITED_ALLOWANCE: &str = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+enum AllowanceOp {
+    Permit(contract::Permit),
+    Reset { owner: Vec<u8>, spender: Vec<u8> },
+}
+
+enum RemainingOp {
+    Grant(String, BigInt),
+    Spend(String, BigInt),
+    Reset(String),
+}
+
+pub fn permit_key(owner: &[u8], spender: &[u8]) -> String {
+    format!("permit:{}:{}", Hex(owner), Hex(spender))
+}
+
+fn seconds(time: &Option<prost_types::Timestamp>) -> i64 {
+    time.as_ref().map(|t| t.seconds).unwrap_or_default()
+}
+
+/// Keeps the last successful `permit` per (owner, spender). An `approve`,
+/// `increaseAllowance` or `decreaseAllowance` sent by the owner overrides the
+/// permitted allowance, so it drops the entry.
+#[substreams::handlers::store]
+fn store_permits(calls: contract::Calls, store: StoreSetProto<contract::Permit>) {
+    let mut ops: Vec<(u64, AllowanceOp)> = owner_resets(&calls);
+
+    ops.extend(calls.usdc_call_permit_1s.into_iter()
+        .filter(|call| call.call_success)
+        .map(|call| (call.call_ordinal, AllowanceOp::Permit(contract::Permit {
+            permit_tx_hash: call.call_tx_hash,
+            permit_block_time: call.call_block_time,
+            permit_block_number: call.call_block_number,
+            permit_ordinal: call.call_ordinal,
+            owner: call.owner,
+            spender: call.spender,
+            value: call.value,
+            deadline: call.deadline,
+        }))));
+    ops.extend(calls.usdc_call_permit_2s.into_iter()
+        .filter(|call| call.call_success)
+        .map(|call| (call.call_ordinal, AllowanceOp::Permit(contract::Permit {
+            permit_tx_hash: call.call_tx_hash,
+            permit_block_time: call.call_block_time,
+            permit_block_number: call.call_block_number,
+            permit_ordinal: call.call_ordinal,
+            owner: call.owner,
+            spender: call.spender,
+            value: call.value,
+            deadline: call.deadline,
+        }))));
+
+    ops.sort_by_key(|(ordinal, _)| *ordinal);
+    for (ordinal, op) in ops {
+        match op {
+            AllowanceOp::Permit(permit) => {
+                store.set(ordinal, permit_key(&permit.owner, &permit.spender), &permit);
+            }
+            AllowanceOp::Reset { owner, spender } => {
+                store.delete_prefix(ordinal as i64, &permit_key(&owner, &spender));
+            }
+        }
+    }
+}
+
+/// Tracks the allowance left of each permit in `store_permits`: the permitted
+/// value, reset by every new permit and decreased by each successful
+/// `transferFrom` of the spender at the end of the call. The entry is
+/// dropped when the owner overrides the allowance. Unlimited permits are
+/// never decreased, like the contract's allowance.
+#[substreams::handlers::store]
+fn store_permit_allowances(calls: contract::Calls, permits: StoreGetProto<contract::Permit>, store: StoreAddBigInt) {
+    let mut ops: Vec<(u64, RemainingOp)> = owner_resets(&calls).into_iter()
+        .filter_map(|(ordinal, op)| match op {
+            AllowanceOp::Reset { owner, spender } => Some((ordinal, RemainingOp::Reset(permit_key(&owner, &spender)))),
+            AllowanceOp::Permit(_) => None,
+        })
+        .collect();
+
+    ops.extend(calls.usdc_call_permit_1s.iter()
+        .filter(|call| call.call_success)
+        .map(|call| (call.call_ordinal, RemainingOp::Grant(permit_key(&call.owner, &call.spender), BigInt::from_str(&call.value).unwrap_or_default()))));
+    ops.extend(calls.usdc_call_permit_2s.iter()
+        .filter(|call| call.call_success)
+        .map(|call| (call.call_ordinal, RemainingOp::Grant(permit_key(&call.owner, &call.spender), BigInt::from_str(&call.value).unwrap_or_default()))));
+    ops.extend(calls.usdc_call_transfer_froms.iter()
+        .filter(|call| call.call_success)
+        .filter_map(|call| {
+            let key = permit_key(&call.from, &call.call_caller);
+            let permit = permits.get_at(call.call_ordinal, &key)?;
+            if permit.value == UNLIMITED_ALLOWANCE {
+                return None;
+            }
+
+            Some((call.call_end_ordinal, RemainingOp::Spend(key, BigInt::from_str(&call.value).unwrap_or_default())))
+        }));
+
+    ops.sort_by_key(|(ordinal, _)| *ordinal);
+    for (ordinal, op) in ops {
+        match op {
+            RemainingOp::Grant(key, value) => {
+                store.delete_prefix(ordinal as i64, &key);
+                store.add(ordinal, key, value);
+            }
+            RemainingOp::Spend(key, value) => {
+                store.add(ordinal, key, value.neg());
+            }
+            RemainingOp::Reset(key) => {
+                store.delete_prefix(ordinal as i64, &key);
+            }
+        }
+    }
+}
+
+/// Successful allowance changes sent by the owner, which override any
+/// permitted allowance.
+fn owner_resets(calls: &contract::Calls) -> Vec<(u64, AllowanceOp)> {
+    let approves = calls.usdc_call_approves.iter()
+        .filter(|call| call.call_success)
+        .map(|call| (call.call_ordinal, &call.call_caller, &call.spender));
+    let increases = calls.usdc_call_increase_allowances.iter()
+        .filter(|call| call.call_success)
+        .map(|call| (call.call_ordinal, &call.call_caller, &call.spender));
+    let decreases = calls.usdc_call_decrease_allowances.iter()
+        .filter(|call| call.call_success)
+        .map(|call| (call.call_ordinal, &call.call_caller, &call.spender));
+
+    approves.chain(increases).chain(decreases)
+        .map(|(ordinal, owner, spender)| (ordinal, AllowanceOp::Reset { owner: owner.clone(), spender: spender.clone() }))
+        .collect()
+}
+
+/// Links every successful `transferFrom` to the permit that granted its
+/// allowance, as seen by the stores at the call's ordinal, until the
+/// permitted allowance is spent.
+#[substreams::handlers::map]
+fn map_permit_spends(
+    calls: contract::Calls,
+    permits: StoreGetProto<contract::Permit>,
+    allowances: StoreGetBigInt,
+) -> Result<contract::PermitSpends, substreams::errors::Error> {
+    let permit_spends = calls.usdc_call_transfer_froms.into_iter()
+        .filter(|call| call.call_success)
+        .filter_map(|call| {
+            let key = permit_key(&call.from, &call.call_caller);
+            let permit = permits.get_at(call.call_ordinal, &key)?;
+            let remaining = allowances.get_at(call.call_ordinal, &key)?;
+            if remaining <= BigInt::zero() {
+                return None;
+            }
+            let remaining_value = if permit.value == UNLIMITED_ALLOWANCE {
+                remaining
+            } else {
+                (remaining - BigInt::from_str(&call.value).unwrap_or_default()).max(BigInt::zero())
+            };
+
+            Some(contract::PermitSpend {
+                same_tx: permit.permit_tx_hash == call.call_tx_hash,
+                seconds_since_permit: (seconds(&call.call_block_time) - seconds(&permit.permit_block_time)).max(0) as u64,
+                blocks_since_permit: call.call_block_number.saturating_sub(permit.permit_block_number),
+                permit_tx_hash: permit.permit_tx_hash,
+                permit_block_time: permit.permit_block_time,
+                permit_block_number: permit.permit_block_number,
+                permit_ordinal: permit.permit_ordinal,
+                spend_tx_hash: call.call_tx_hash,
+                spend_block_time: call.call_block_time,
+                spend_block_number: call.call_block_number,
+                spend_ordinal: call.call_ordinal,
+                owner: call.from,
+                spender: call.call_caller,
+                to: call.to,
+                permit_value: permit.value,
+                spend_value: call.value,
+                deadline: permit.deadline,
+                remaining_value: remaining_value.to_string(),
+            })
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::PermitSpends { permit_spends })
+}
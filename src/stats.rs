@@ -0,0 +1,127 @@
+use crate::calls::for_each_call_field;
+use crate::dates::date_of;
+use crate::pb::contract::v1 as contract;
+use crate::ZERO_ADDRESS;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreDelete, StoreGet, StoreGetBigInt, StoreNew};
+use std::collections::{BTreeMap, HashSet};
+use std::str::FromStr;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+macro_rules! count_failed_calls {
+    ($calls:expr, $failed:expr, $($field:ident: $variant:ident => $function:literal,)*) => {
+        $(
+            let count = $calls.$field.iter().filter(|call| !call.call_success).count() as u64;
+            if count > 0 {
+                *$failed.entry($function).or_default() += count;
+            }
+        )*
+    };
+}
+
+fn failed_calls(calls: &contract::Calls) -> Vec<contract::FunctionFailures> {
+    let mut failed: BTreeMap<&str, u64> = BTreeMap::new();
+    for_each_call_field!(count_failed_calls!(calls, failed,));
+
+    failed.into_iter()
+        .map(|(function, count)| contract::FunctionFailures { function: function.to_string(), count })
+        .collect()
+}
+
+fn sum<'a>(values: impl Iterator<Item = &'a String>) -> BigInt {
+    values.fold(BigInt::zero(), |total, value| total + BigInt::from_str(value).unwrap_or_default())
+}
+
+fn day_of(time: &Option<prost_types::Timestamp>) -> i64 {
+    time.as_ref().map(|t| t.seconds).unwrap_or_default().div_euclid(SECONDS_PER_DAY)
+}
+
+fn day_key(day: i64, counter: &str) -> String {
+    format!("day:{}:{}", day, counter)
+}
+
+#[substreams::handlers::map]
+fn map_block_stats(
+    clock: Clock,
+    events: contract::Events,
+    calls: contract::Calls,
+) -> Result<contract::BlockStats, substreams::errors::Error> {
+    let senders: HashSet<&Vec<u8>> = events.usdc_transfers.iter()
+        .map(|transfer| &transfer.from)
+        .filter(|from| from.as_slice() != ZERO_ADDRESS)
+        .collect();
+    let receivers: HashSet<&Vec<u8>> = events.usdc_transfers.iter()
+        .map(|transfer| &transfer.to)
+        .filter(|to| to.as_slice() != ZERO_ADDRESS)
+        .collect();
+
+    Ok(contract::BlockStats {
+        block_number: clock.number,
+        block_time: clock.timestamp,
+        transfer_count: events.usdc_transfers.len() as u64,
+        transfer_volume: sum(events.usdc_transfers.iter().map(|transfer| &transfer.value)).to_string(),
+        unique_senders: senders.len() as u64,
+        unique_receivers: receivers.len() as u64,
+        mint_count: events.usdc_mints.len() as u64,
+        mint_volume: sum(events.usdc_mints.iter().map(|mint| &mint.amount)).to_string(),
+        burn_count: events.usdc_burns.len() as u64,
+        burn_volume: sum(events.usdc_burns.iter().map(|burn| &burn.amount)).to_string(),
+        approval_count: events.usdc_approvals.len() as u64,
+        failed_calls: failed_calls(&calls),
+    })
+}
+
+/// Accumulates `BlockStats` per UTC day. Counters of days older than the
+/// previous one are dropped, since `map_daily_stats` no longer reads them.
+#[substreams::handlers::store]
+fn store_daily_stats(block_stats: contract::BlockStats, store: StoreAddBigInt) {
+    let day = day_of(&block_stats.block_time);
+    let ordinal = 0;
+
+    store.delete_prefix(ordinal as i64, &format!("day:{}:", day - 2));
+    store.add(ordinal, day_key(day, "block_count"), BigInt::one());
+    store.add(ordinal, day_key(day, "transfer_count"), BigInt::from(block_stats.transfer_count));
+    store.add(ordinal, day_key(day, "transfer_volume"), BigInt::from_str(&block_stats.transfer_volume).unwrap_or_default());
+    store.add(ordinal, day_key(day, "mint_count"), BigInt::from(block_stats.mint_count));
+    store.add(ordinal, day_key(day, "mint_volume"), BigInt::from_str(&block_stats.mint_volume).unwrap_or_default());
+    store.add(ordinal, day_key(day, "burn_count"), BigInt::from(block_stats.burn_count));
+    store.add(ordinal, day_key(day, "burn_volume"), BigInt::from_str(&block_stats.burn_volume).unwrap_or_default());
+    store.add(ordinal, day_key(day, "approval_count"), BigInt::from(block_stats.approval_count));
+    store.add(
+        ordinal,
+        day_key(day, "failed_call_count"),
+        BigInt::from(block_stats.failed_calls.iter().map(|failures| failures.count).sum::<u64>()),
+    );
+}
+
+/// Emits the totals of the previous UTC day on the first block of a new day.
+#[substreams::handlers::map]
+fn map_daily_stats(
+    block_stats: contract::BlockStats,
+    daily_stats: StoreGetBigInt,
+) -> Result<contract::DailyStats, substreams::errors::Error> {
+    let today = day_of(&block_stats.block_time);
+    let yesterday = today - 1;
+
+    if daily_stats.has_first(day_key(today, "block_count")) || !daily_stats.has_last(day_key(yesterday, "block_count")) {
+        substreams::skip_empty_output();
+        return Ok(contract::DailyStats::default());
+    }
+
+    let counter = |name: &str| daily_stats.get_last(day_key(yesterday, name)).unwrap_or_default();
+    Ok(contract::DailyStats {
+        date: date_of(yesterday),
+        day_start: Some(prost_types::Timestamp { seconds: yesterday * SECONDS_PER_DAY, nanos: 0 }),
+        block_count: counter("block_count").to_u64(),
+        transfer_count: counter("transfer_count").to_u64(),
+        transfer_volume: counter("transfer_volume").to_string(),
+        mint_count: counter("mint_count").to_u64(),
+        mint_volume: counter("mint_volume").to_string(),
+        burn_count: counter("burn_count").to_u64(),
+        burn_volume: counter("burn_volume").to_string(),
+        approval_count: counter("approval_count").to_u64(),
+        failed_call_count: counter("failed_call_count").to_u64(),
+    })
+}
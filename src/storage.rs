@@ -0,0 +1,221 @@
+use crate::pb::contract::v1 as contract;
+use crate::USDC_TRACKED_CONTRACT;
+use std::collections::HashMap;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// Slot of `balanceAndBlacklistStates`, `balances` before FiatTokenV2_2, in
+/// the FiatToken storage layout.
+const BALANCES_SLOT: u8 = 9;
+/// Slot of `allowed`, the allowances by owner then spender.
+const ALLOWANCES_SLOT: u8 = 10;
+/// Since FiatTokenV2_2, the high bit of a balance slot is the blacklist flag.
+const BLACKLIST_FLAG: u8 = 0x80;
+
+fn slot_word(slot: u8) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[31] = slot;
+    word
+}
+
+/// Keccak preimages of a transaction, by hash.
+fn keccak_preimages(tx: &eth::TransactionTrace) -> HashMap<Vec<u8>, Vec<u8>> {
+    tx.calls.iter()
+        .flat_map(|call| call.keccak_preimages.iter())
+        .filter_map(|(hash, preimage)| Some((Hex::decode(hash).ok()?, Hex::decode(preimage).ok()?)))
+        .collect()
+}
+
+/// Splits the slot of a `mapping(address => ...)` entry into the key address
+/// and the slot of the mapping, when its preimage was traced.
+fn mapping_entry<'a>(preimages: &'a HashMap<Vec<u8>, Vec<u8>>, slot: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let preimage = preimages.get(slot).filter(|preimage| preimage.len() == 64)?;
+    let (key, mapping) = preimage.split_at(32);
+    Some((&key[12..], mapping))
+}
+
+fn balance(value: &[u8]) -> (BigInt, bool) {
+    let mut value = value.to_vec();
+    let blacklisted = value.first().is_some_and(|byte| byte & BLACKLIST_FLAG != 0);
+    if let Some(byte) = value.first_mut() {
+        *byte &= !BLACKLIST_FLAG;
+    }
+    (BigInt::from_unsigned_bytes_be(&value), blacklisted)
+}
+
+/// Writes to a balance or allowance slot of the tracked contract in the
+/// block, mapped back to their addresses through the keccak preimages.
+fn state_changes(blk: &eth::Block) -> contract::StateChanges {
+    let balances_slot = slot_word(BALANCES_SLOT);
+    let allowances_slot = slot_word(ALLOWANCES_SLOT);
+    let mut state_changes = contract::StateChanges::default();
+
+    for tx in blk.transactions() {
+        let preimages = keccak_preimages(tx);
+        let storage_changes = tx.calls.iter()
+            .filter(|call| !call.state_reverted)
+            .flat_map(|call| call.storage_changes.iter())
+            .filter(|change| change.address == USDC_TRACKED_CONTRACT);
+
+        for change in storage_changes {
+            let Some((key, mapping)) = mapping_entry(&preimages, &change.key) else {
+                continue;
+            };
+
+            if mapping == balances_slot {
+                let (old, _) = balance(&change.old_value);
+                let (new, blacklisted) = balance(&change.new_value);
+                if old == new {
+                    continue;
+                }
+
+                state_changes.balance_changes.push(contract::BalanceChange {
+                    tx_hash: Hex(&tx.hash).to_string(),
+                    block_time: Some(blk.timestamp().to_owned()),
+                    block_number: blk.number,
+                    ordinal: change.ordinal,
+                    address: key.to_vec(),
+                    old: old.to_string(),
+                    new: new.to_string(),
+                    blacklisted,
+                });
+            } else if let Some((owner, _)) = mapping_entry(&preimages, mapping).filter(|(_, inner)| *inner == allowances_slot) {
+                state_changes.allowance_changes.push(contract::AllowanceChange {
+                    tx_hash: Hex(&tx.hash).to_string(),
+                    block_time: Some(blk.timestamp().to_owned()),
+                    block_number: blk.number,
+                    ordinal: change.ordinal,
+                    owner: owner.to_vec(),
+                    spender: key.to_vec(),
+                    old: BigInt::from_unsigned_bytes_be(&change.old_value).to_string(),
+                    new: BigInt::from_unsigned_bytes_be(&change.new_value).to_string(),
+                });
+            }
+        }
+    }
+
+    state_changes
+}
+
+/// Extracts every write to a balance or allowance slot of the tracked
+/// contract from the storage changes of the block, including the ones that
+/// emit no event. Slots are mapped back to their addresses through the
+/// keccak preimages of the transaction, following the FiatToken storage
+/// layout: `balanceAndBlacklistStates` at slot 9 and `allowed` at slot 10.
+/// Writes that only flip the blacklist flag of a balance are not reported.
+#[substreams::handlers::map]
+fn map_state_changes(blk: eth::Block) -> Result<contract::StateChanges, substreams::errors::Error> {
+    let state_changes = state_changes(&blk);
+
+    substreams::skip_empty_output();
+    Ok(state_changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_blocks;
+    use hex_literal::hex;
+    use tiny_keccak::{Hasher, Keccak};
+
+    const HOLDER: [u8; 20] = hex!("1111111111111111111111111111111111111111");
+    const SPENDER: [u8; 20] = hex!("2222222222222222222222222222222222222222");
+
+    fn word(bytes: &[u8]) -> Vec<u8> {
+        let mut word = vec![0u8; 32 - bytes.len()];
+        word.extend_from_slice(bytes);
+        word
+    }
+
+    /// Slot of the `key` entry of the mapping at `slot`, with its preimage.
+    fn entry_slot(key: &[u8], slot: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let preimage = [word(key), slot.to_vec()].concat();
+        let mut hash = [0u8; 32];
+        let mut keccak = Keccak::v256();
+        keccak.update(&preimage);
+        keccak.finalize(&mut hash);
+        (hash.to_vec(), preimage)
+    }
+
+    fn block(preimages: Vec<(Vec<u8>, Vec<u8>)>, storage_changes: Vec<eth::StorageChange>) -> eth::Block {
+        let call = eth::Call {
+            address: USDC_TRACKED_CONTRACT.to_vec(),
+            keccak_preimages: preimages.into_iter().map(|(hash, preimage)| (Hex(hash).to_string(), Hex(preimage).to_string())).collect(),
+            storage_changes,
+            ..Default::default()
+        };
+
+        test_blocks::block(eth::TransactionTrace {
+            status: eth::TransactionTraceStatus::Succeeded as i32,
+            calls: vec![call],
+            ..Default::default()
+        })
+    }
+
+    fn storage_change(key: Vec<u8>, old_value: Vec<u8>, new_value: Vec<u8>) -> eth::StorageChange {
+        eth::StorageChange { address: USDC_TRACKED_CONTRACT.to_vec(), key, old_value, new_value, ordinal: 7 }
+    }
+
+    #[test]
+    fn balance_slot() {
+        let (slot, preimage) = entry_slot(&HOLDER, &slot_word(BALANCES_SLOT));
+        let mut blacklisted = word(&[40]);
+        blacklisted[0] = BLACKLIST_FLAG;
+        let blk = block(vec![(slot.clone(), preimage)], vec![storage_change(slot, word(&[100]), blacklisted)]);
+
+        let state_changes = state_changes(&blk);
+        assert_eq!(state_changes.balance_changes.len(), 1);
+        assert!(state_changes.allowance_changes.is_empty());
+        let change = &state_changes.balance_changes[0];
+        assert_eq!(change.address, HOLDER.to_vec());
+        assert_eq!(change.old, "100");
+        assert_eq!(change.new, "40");
+        assert!(change.blacklisted);
+        assert_eq!(change.ordinal, 7);
+    }
+
+    #[test]
+    fn blacklist_flag_only() {
+        let (slot, preimage) = entry_slot(&HOLDER, &slot_word(BALANCES_SLOT));
+        let mut blacklisted = word(&[100]);
+        blacklisted[0] = BLACKLIST_FLAG;
+        let blk = block(vec![(slot.clone(), preimage)], vec![storage_change(slot, word(&[100]), blacklisted)]);
+
+        assert!(state_changes(&blk).balance_changes.is_empty());
+    }
+
+    #[test]
+    fn allowance_slot() {
+        let (inner, inner_preimage) = entry_slot(&HOLDER, &slot_word(ALLOWANCES_SLOT));
+        let (slot, preimage) = entry_slot(&SPENDER, &inner);
+        let blk = block(
+            vec![(inner, inner_preimage), (slot.clone(), preimage)],
+            vec![storage_change(slot, word(&[0]), word(&[0x01, 0x00]))],
+        );
+
+        let state_changes = state_changes(&blk);
+        assert!(state_changes.balance_changes.is_empty());
+        assert_eq!(state_changes.allowance_changes.len(), 1);
+        let change = &state_changes.allowance_changes[0];
+        assert_eq!(change.owner, HOLDER.to_vec());
+        assert_eq!(change.spender, SPENDER.to_vec());
+        assert_eq!(change.old, "0");
+        assert_eq!(change.new, "256");
+    }
+
+    #[test]
+    fn other_slots() {
+        // An entry of another mapping, and a slot without a traced preimage.
+        let (slot, preimage) = entry_slot(&HOLDER, &slot_word(3));
+        let (untraced, _) = entry_slot(&SPENDER, &slot_word(BALANCES_SLOT));
+        let blk = block(
+            vec![(slot.clone(), preimage)],
+            vec![storage_change(slot, word(&[0]), word(&[1])), storage_change(untraced, word(&[0]), word(&[1]))],
+        );
+
+        let state_changes = state_changes(&blk);
+        assert!(state_changes.balance_changes.is_empty());
+        assert!(state_changes.allowance_changes.is_empty());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::abi::usdc_contract::functions::BalanceOf;
+use crate::balances::balance_key;
+use crate::metadata::FIRST_BLOCK_KEY;
+use crate::params::Params;
+use crate::pb::contract::v1 as contract;
+use crate::permits::UNLIMITED_ALLOWANCE;
+use crate::USDC_TRACKED_CONTRACT;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    DeltaInt64, Deltas, StoreAdd, StoreAddInt64, StoreGet, StoreGetBigInt, StoreGetInt64, StoreNew,
+    StoreSetIfNotExists, StoreSetIfNotExistsInt64,
+};
+use substreams::{key, Hex};
+use substreams_ethereum::rpc::RpcBatch;
+use std::str::FromStr;
+
+struct Thresholds {
+    fresh_spender_blocks: u64,
+    drain_window_blocks: u64,
+    surge_approvers: i64,
+}
+
+impl Thresholds {
+    fn from_params(params: &str) -> Result<Self, substreams::errors::Error> {
+        let params = Params::parse(params)?;
+
+        Ok(Thresholds {
+            fresh_spender_blocks: params.parse_or("fresh_spender_blocks", 7200)?,
+            drain_window_blocks: params.parse_or("drain_window_blocks", 50)?,
+            surge_approvers: params.parse_or("surge_approvers", 100)?,
+        })
+    }
+}
+
+fn spender_key(spender: &[u8]) -> String {
+    format!("spender:{}", Hex(spender))
+}
+
+fn approval_key(spender: &[u8], owner: &[u8]) -> String {
+    format!("approval:{}:{}", Hex(spender), Hex(owner))
+}
+
+fn approvers_key(spender: &str) -> String {
+    format!("approvers:{}", spender)
+}
+
+/// Records the block in which each spender, and each (spender, owner) pair,
+/// was first granted a non-zero allowance.
+#[substreams::handlers::store]
+fn store_spender_approvals(events: contract::Events, store: StoreSetIfNotExistsInt64) {
+    for approval in events.usdc_approvals {
+        if approval.value == "0" {
+            continue;
+        }
+
+        let ordinal = approval.evt_ordinal;
+        let block_number = approval.evt_block_number as i64;
+        store.set_if_not_exists(ordinal, spender_key(&approval.spender), &block_number);
+        store.set_if_not_exists(ordinal, approval_key(&approval.spender, &approval.owner), &block_number);
+    }
+}
+
+/// Counts the distinct owners that approved each spender.
+#[substreams::handlers::store]
+fn store_spender_approvers(deltas: Deltas<DeltaInt64>, store: StoreAddInt64) {
+    for delta in deltas.deltas {
+        if delta.operation != Operation::Create {
+            continue;
+        }
+
+        if key::first_segment(&delta.key) == "approval" {
+            store.add(delta.ordinal, approvers_key(key::segment_at(&delta.key, 1)), 1);
+        }
+    }
+}
+
+/// Balance of each owner right after its spend: the on-chain `balanceOf` at
+/// the end of the block, minus the net change tracked by `store_balances`
+/// from the spend's ordinal to the end of the block, minus the spend itself.
+fn balances_after(spends: &[contract::PermitSpend], balances: &StoreGetBigInt) -> Result<Vec<Option<BigInt>>, substreams::errors::Error> {
+    if spends.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let responses = spends.iter()
+        .fold(RpcBatch::new(), |batch, spend| {
+            batch.add(BalanceOf { account: spend.owner.clone() }, USDC_TRACKED_CONTRACT.to_vec())
+        })
+        .execute()
+        .map_err(substreams::errors::Error::msg)?
+        .responses;
+
+    Ok(spends.iter()
+        .zip(responses)
+        .map(|(spend, response)| {
+            let end_of_block = RpcBatch::decode::<_, BalanceOf>(&response)?;
+            let key = balance_key(&spend.owner);
+            let later_change = balances.get_last(&key).unwrap_or_default()
+                - balances.get_at(spend.spend_ordinal, &key).unwrap_or_default();
+
+            Some(end_of_block - later_change - BigInt::from_str(&spend.spend_value).unwrap_or_default())
+        })
+        .collect())
+}
+
+/// Flags unlimited approvals to fresh spenders, permit drains and spender
+/// approval surges. A spender is only known to be fresh once the stream has
+/// run for `fresh_spender_blocks` blocks, since every spender is first seen
+/// at the start of the stream, so the fresh-spender check is skipped during
+/// that warm-up window.
+#[substreams::handlers::map]
+fn map_suspicious_activity(
+    params: String,
+    events: contract::Events,
+    permit_spends: contract::PermitSpends,
+    spender_approvals: StoreGetInt64,
+    spender_approvers: StoreGetInt64,
+    balances: StoreGetBigInt,
+    first_block: StoreGetInt64,
+) -> Result<contract::SuspiciousActivities, substreams::errors::Error> {
+    let thresholds = Thresholds::from_params(&params)?;
+    let warm_up_end = first_block.get_last(FIRST_BLOCK_KEY).unwrap_or_default() as u64 + thresholds.fresh_spender_blocks;
+    let mut suspicious_activities = Vec::new();
+
+    for approval in &events.usdc_approvals {
+        if approval.value != UNLIMITED_ALLOWANCE || approval.evt_block_number < warm_up_end {
+            continue;
+        }
+
+        let first_seen = spender_approvals
+            .get_last(spender_key(&approval.spender))
+            .unwrap_or(approval.evt_block_number as i64) as u64;
+        if approval.evt_block_number.saturating_sub(first_seen) > thresholds.fresh_spender_blocks {
+            continue;
+        }
+
+        suspicious_activities.push(contract::SuspiciousActivity {
+            kind: contract::SuspicionKind::UnlimitedApprovalToFreshSpender as i32,
+            tx_hash: approval.evt_tx_hash.clone(),
+            block_time: approval.evt_block_time.clone(),
+            block_number: approval.evt_block_number,
+            owner: approval.owner.clone(),
+            spender: approval.spender.clone(),
+            value: approval.value.clone(),
+            spender_first_seen_block: first_seen,
+            ..Default::default()
+        });
+    }
+
+    let spends: Vec<contract::PermitSpend> = permit_spends.permit_spends.into_iter()
+        .filter(|spend| spend.blocks_since_permit <= thresholds.drain_window_blocks)
+        .collect();
+    let remaining_balances = balances_after(&spends, &balances)?;
+    for (spend, remaining) in spends.into_iter().zip(remaining_balances) {
+        let Some(remaining) = remaining else {
+            continue;
+        };
+        if remaining > BigInt::zero() {
+            continue;
+        }
+
+        suspicious_activities.push(contract::SuspiciousActivity {
+            kind: contract::SuspicionKind::PermitDrain as i32,
+            tx_hash: spend.spend_tx_hash,
+            block_time: spend.spend_block_time,
+            block_number: spend.spend_block_number,
+            owner: spend.owner,
+            spender: spend.spender,
+            value: spend.spend_value,
+            blocks_since_permit: spend.blocks_since_permit,
+            remaining_balance: remaining.to_string(),
+            ..Default::default()
+        });
+    }
+
+    let mut surging: Vec<Vec<u8>> = Vec::new();
+    for approval in &events.usdc_approvals {
+        if surging.contains(&approval.spender) {
+            continue;
+        }
+
+        let approvers = approvers_key(&Hex(&approval.spender).to_string());
+        let before = spender_approvers.get_first(&approvers).unwrap_or_default();
+        let after = spender_approvers.get_last(&approvers).unwrap_or_default();
+        if before >= thresholds.surge_approvers || after < thresholds.surge_approvers {
+            continue;
+        }
+
+        surging.push(approval.spender.clone());
+        suspicious_activities.push(contract::SuspiciousActivity {
+            kind: contract::SuspicionKind::SpenderApprovalSurge as i32,
+            tx_hash: approval.evt_tx_hash.clone(),
+            block_time: approval.evt_block_time.clone(),
+            block_number: approval.evt_block_number,
+            spender: approval.spender.clone(),
+            approver_count: after,
+            ..Default::default()
+        });
+    }
+
+    substreams::skip_empty_output();
+    Ok(contract::SuspiciousActivities { suspicious_activities })
+}
@@ -0,0 +1,17 @@
+use substreams_ethereum::pb::eth::v2 as eth;
+
+pub const BLOCK_NUMBER: u64 = 21_000_000;
+
+/// Block holding the single transaction `tx`, with a fixed number, timestamp
+/// and transaction hash, for the handler tests.
+pub fn block(tx: eth::TransactionTrace) -> eth::Block {
+    eth::Block {
+        number: BLOCK_NUMBER,
+        header: Some(eth::BlockHeader {
+            timestamp: Some(prost_types::Timestamp { seconds: 1_729_000_000, nanos: 0 }),
+            ..Default::default()
+        }),
+        transaction_traces: vec![eth::TransactionTrace { hash: vec![0xab; 32], ..tx }],
+        ..Default::default()
+    }
+}
@@ -0,0 +1,125 @@
+use crate::abi::usdc_contract::{events, functions};
+use crate::pb::contract::v1 as contract;
+use crate::USDC_TRACKED_CONTRACT;
+use hex_literal::hex;
+use substreams::store::{StoreGet, StoreGetProto, StoreGetRaw, StoreNew, StoreSet, StoreSetProto, StoreSetRaw};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::{Event, Function};
+
+/// Storage slot of the proxy's implementation address, the keccak-256 hash
+/// of `org.zeppelinos.proxy.implementation`.
+const IMPLEMENTATION_SLOT: [u8; 32] = hex!("7050c9e0f4ca769c69bd3a8ef740bc37934f8e2c036e5a723fd8ee048ed3f8c3");
+const IMPLEMENTATION_KEY: &str = "implementation";
+
+fn code_key(address: &[u8]) -> String {
+    format!("code:{}", Hex(address))
+}
+
+/// Implementation the proxy pointed to before the upgrade of `call`, from
+/// the old value of its implementation slot.
+fn previous_implementation_slot(call: &eth::Call) -> Option<Vec<u8>> {
+    call.storage_changes.iter()
+        .find(|change| change.address == USDC_TRACKED_CONTRACT && change.key == IMPLEMENTATION_SLOT)
+        .and_then(|change| change.old_value.get(12..))
+        .map(<[u8]>::to_vec)
+}
+
+/// Decodes the calldata forwarded by `upgradeToAndCall` as one of the
+/// `initializeV2*` functions, filling the matching fields of `record`.
+fn decode_initializer(data: &[u8], record: &mut contract::UpgradeRecord) {
+    let call = eth::Call { input: data.to_vec(), ..Default::default() };
+
+    if let Some(initialize) = functions::InitializeV2::match_and_decode(&call) {
+        record.initializer = functions::InitializeV2::NAME.to_string();
+        record.new_name = initialize.new_name;
+    } else if let Some(initialize) = functions::InitializeV21::match_and_decode(&call) {
+        record.initializer = functions::InitializeV21::NAME.to_string();
+        record.lost_and_found = initialize.lost_and_found;
+    } else if let Some(initialize) = functions::InitializeV22::match_and_decode(&call) {
+        record.initializer = functions::InitializeV22::NAME.to_string();
+        record.accounts_to_blacklist = initialize.accounts_to_blacklist;
+        record.new_symbol = initialize.new_symbol;
+    }
+}
+
+/// Keeps the current implementation of the proxy, from its `Upgraded`
+/// events.
+#[substreams::handlers::store]
+fn store_implementation(events: contract::Events, store: StoreSetRaw) {
+    for upgraded in events.usdc_upgradeds {
+        store.set(upgraded.evt_ordinal, IMPLEMENTATION_KEY, &upgraded.implementation);
+    }
+}
+
+/// Keeps the code hash and size of every contract deployed since the
+/// initial block, so that upgrades can be matched to the code of their
+/// implementations.
+#[substreams::handlers::store]
+fn store_deployed_code(blk: eth::Block, store: StoreSetProto<contract::DeployedCode>) {
+    for change in blk.transactions().flat_map(|tx| tx.calls.iter()).flat_map(|call| call.code_changes.iter()) {
+        store.set(change.ordinal, code_key(&change.address), &contract::DeployedCode {
+            code_hash: change.new_hash.clone(),
+            code_size: change.new_code.len() as u64,
+        });
+    }
+}
+
+/// Emits an audit record for every `Upgraded` event of the proxy, with the
+/// function and admin that performed the upgrade, the calldata forwarded by
+/// `upgradeToAndCall` and the `initializeV2*` arguments it decodes to, and
+/// the previous and new implementations with their code hash and size. The
+/// previous implementation comes from `store_implementation`, or from the
+/// implementation slot for the first upgrade seen. Code hashes come from
+/// `store_deployed_code`, so they stay empty for implementations deployed
+/// before the initial block.
+#[substreams::handlers::map]
+fn map_upgrade_records(
+    blk: eth::Block,
+    implementations: StoreGetRaw,
+    deployed_code: StoreGetProto<contract::DeployedCode>,
+) -> Result<contract::UpgradeRecords, substreams::errors::Error> {
+    let upgrade_records = blk.transactions()
+        .flat_map(|tx| {
+            tx.calls.iter()
+                .filter(|call| call.address == USDC_TRACKED_CONTRACT && !call.state_reverted)
+                .flat_map(move |call| call.logs.iter().map(move |log| (tx, call, log)))
+        })
+        .filter(|(_, _, log)| log.address == USDC_TRACKED_CONTRACT)
+        .filter_map(|(tx, call, log)| {
+            let upgraded = events::Upgraded::match_and_decode(log)?;
+            let previous_implementation = implementations.get_at(log.ordinal.saturating_sub(1), IMPLEMENTATION_KEY)
+                .or_else(|| previous_implementation_slot(call))
+                .unwrap_or_default();
+            let code = deployed_code.get_at(log.ordinal, code_key(&upgraded.implementation)).unwrap_or_default();
+            let previous_code = deployed_code.get_at(log.ordinal, code_key(&previous_implementation)).unwrap_or_default();
+
+            let mut record = contract::UpgradeRecord {
+                tx_hash: Hex(&tx.hash).to_string(),
+                block_time: Some(blk.timestamp().to_owned()),
+                block_number: blk.number,
+                ordinal: log.ordinal,
+                admin: call.caller.clone(),
+                code_hash: code.code_hash,
+                code_size: code.code_size,
+                implementation: upgraded.implementation,
+                previous_implementation,
+                previous_code_hash: previous_code.code_hash,
+                previous_code_size: previous_code.code_size,
+                ..Default::default()
+            };
+            if let Some(upgrade) = functions::UpgradeToAndCall::match_and_decode(call) {
+                record.function = functions::UpgradeToAndCall::NAME.to_string();
+                decode_initializer(&upgrade.data, &mut record);
+                record.call_data = upgrade.data;
+            } else if functions::UpgradeTo::match_call(call) {
+                record.function = functions::UpgradeTo::NAME.to_string();
+            }
+
+            Some(record)
+        })
+        .collect();
+
+    substreams::skip_empty_output();
+    Ok(contract::UpgradeRecords { upgrade_records })
+}
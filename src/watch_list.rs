@@ -0,0 +1,184 @@
+use crate::metadata::FIRST_BLOCK_KEY;
+use crate::params::Params;
+use crate::pb::contract::v1 as contract;
+use anyhow::anyhow;
+use substreams::pb::substreams::Clock;
+use substreams::store::{StoreGet, StoreGetInt64, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsInt64};
+use substreams::Hex;
+
+/// Addresses given through the comma-separated `watch_list` param.
+fn watch_list(params: &str) -> Result<Vec<Vec<u8>>, substreams::errors::Error> {
+    let params = Params::parse(params)?;
+    let addresses = params.get("watch_list").ok_or_else(|| anyhow!("missing param watch_list"))?;
+
+    addresses.split(',').map(str::trim).filter(|address| !address.is_empty())
+        .map(|address| match Hex::decode(address) {
+            Ok(bytes) if bytes.len() == 20 => Ok(bytes),
+            _ => Err(anyhow!("invalid address {:?} in param watch_list", address)),
+        })
+        .collect()
+}
+
+fn watched_key(address: &[u8]) -> String {
+    format!("watched:{}", Hex(address))
+}
+
+/// Emits the addresses of the `watch_list` param on the first block
+/// processed only, so that the list is parsed once rather than on every
+/// block.
+#[substreams::handlers::map]
+fn map_watch_list(params: String, clock: Clock, first_block: StoreGetInt64) -> Result<contract::WatchList, substreams::errors::Error> {
+    if first_block.get_last(FIRST_BLOCK_KEY) != Some(clock.number as i64) {
+        substreams::skip_empty_output();
+        return Ok(contract::WatchList::default());
+    }
+
+    Ok(contract::WatchList { addresses: watch_list(&params)? })
+}
+
+/// Keeps the addresses on the watch-list.
+#[substreams::handlers::store]
+fn store_watch_list(watch_list: contract::WatchList, store: StoreSetIfNotExistsInt64) {
+    for address in watch_list.addresses {
+        store.set_if_not_exists(0, watched_key(&address), &1);
+    }
+}
+
+/// Keeps the items of each listed field in which one of the given address
+/// fields is listed.
+macro_rules! retain_listed {
+    ($listed:expr, $messages:expr, $($field:ident => [$($address:ident),*],)*) => {
+        $(
+            $messages.$field.retain(|item| $( $listed(item.$address.as_slice()) )||*);
+        )*
+    };
+}
+
+/// Filters the events and calls down to the activity of the listed
+/// addresses: the transfers they send or receive, the approvals they grant
+/// or receive, the permits they sign or are the spender of, and the calls
+/// they make or that move funds from or to them. Other events are dropped.
+/// The output has the shape of `map_events_calls`, so the sinks read it
+/// unchanged.
+pub fn retain_activity(events: contract::Events, calls: contract::Calls, listed: impl Fn(&[u8]) -> bool) -> contract::EventsCalls {
+    let mut events = contract::Events {
+        usdc_transfers: events.usdc_transfers,
+        usdc_approvals: events.usdc_approvals,
+        ..Default::default()
+    };
+    retain_listed!(listed, events,
+        usdc_transfers => [from, to],
+        usdc_approvals => [owner, spender],
+    );
+
+    let mut calls = calls;
+    retain_listed!(listed, calls,
+        usdc_call_approves => [call_caller, spender],
+        usdc_call_blacklists => [call_caller],
+        usdc_call_burns => [call_caller],
+        usdc_call_cancel_authorization_1s => [call_caller, authorizer],
+        usdc_call_cancel_authorization_2s => [call_caller, authorizer],
+        usdc_call_change_admins => [call_caller],
+        usdc_call_configure_minters => [call_caller],
+        usdc_call_decrease_allowances => [call_caller, spender],
+        usdc_call_increase_allowances => [call_caller, spender],
+        usdc_call_initializes => [call_caller],
+        usdc_call_initialize_v_2s => [call_caller],
+        usdc_call_initialize_v2_1s => [call_caller],
+        usdc_call_initialize_v2_2s => [call_caller],
+        usdc_call_mints => [call_caller, u_to],
+        usdc_call_pauses => [call_caller],
+        usdc_call_permit_1s => [call_caller, owner, spender],
+        usdc_call_permit_2s => [call_caller, owner, spender],
+        usdc_call_receive_with_authorization_1s => [call_caller, from, to],
+        usdc_call_receive_with_authorization_2s => [call_caller, from, to],
+        usdc_call_remove_minters => [call_caller],
+        usdc_call_rescue_erc_20s => [call_caller, to],
+        usdc_call_transfers => [call_caller, to],
+        usdc_call_transfer_froms => [call_caller, from, to],
+        usdc_call_transfer_ownerships => [call_caller],
+        usdc_call_transfer_with_authorization_1s => [call_caller, from, to],
+        usdc_call_transfer_with_authorization_2s => [call_caller, from, to],
+        usdc_call_un_blacklists => [call_caller],
+        usdc_call_unpauses => [call_caller],
+        usdc_call_update_blacklisters => [call_caller],
+        usdc_call_update_master_minters => [call_caller],
+        usdc_call_update_pausers => [call_caller],
+        usdc_call_update_rescuers => [call_caller],
+        usdc_call_upgrade_tos => [call_caller],
+        usdc_call_upgrade_to_and_calls => [call_caller],
+    );
+
+    contract::EventsCalls {
+        events: Some(events),
+        calls: Some(calls),
+    }
+}
+
+/// Filters the events and calls down to the activity of the addresses in
+/// `store_watch_list`.
+#[substreams::handlers::map]
+fn map_watched_events_calls(
+    events: contract::Events,
+    calls: contract::Calls,
+    watched: StoreGetInt64,
+) -> Result<contract::EventsCalls, substreams::errors::Error> {
+    let events_calls = retain_activity(events, calls, |address| watched.has_last(watched_key(address)));
+
+    substreams::skip_empty_output();
+    Ok(events_calls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WATCHED: [u8; 20] = [0x11; 20];
+    const OTHER: [u8; 20] = [0x22; 20];
+
+    fn transfer(from: [u8; 20], to: [u8; 20]) -> contract::UsdcTransfer {
+        contract::UsdcTransfer { from: from.to_vec(), to: to.to_vec(), ..Default::default() }
+    }
+
+    fn approval(owner: [u8; 20], spender: [u8; 20]) -> contract::UsdcApproval {
+        contract::UsdcApproval { owner: owner.to_vec(), spender: spender.to_vec(), ..Default::default() }
+    }
+
+    fn transfer_from(caller: [u8; 20], from: [u8; 20], to: [u8; 20]) -> contract::UsdcTransferFromCall {
+        contract::UsdcTransferFromCall { call_caller: caller.to_vec(), from: from.to_vec(), to: to.to_vec(), ..Default::default() }
+    }
+
+    fn retain(events: contract::Events, calls: contract::Calls) -> contract::EventsCalls {
+        retain_activity(events, calls, |address| address == WATCHED)
+    }
+
+    #[test]
+    fn keeps_the_activity_of_listed_addresses() {
+        let events = contract::Events {
+            usdc_transfers: vec![transfer(WATCHED, OTHER), transfer(OTHER, OTHER)],
+            usdc_approvals: vec![approval(OTHER, WATCHED), approval(OTHER, OTHER)],
+            ..Default::default()
+        };
+        let calls = contract::Calls {
+            usdc_call_transfer_froms: vec![transfer_from(OTHER, OTHER, WATCHED), transfer_from(OTHER, OTHER, OTHER)],
+            ..Default::default()
+        };
+
+        let output = retain(events, calls);
+        let events = output.events.unwrap();
+        assert_eq!(events.usdc_transfers, vec![transfer(WATCHED, OTHER)]);
+        assert_eq!(events.usdc_approvals, vec![approval(OTHER, WATCHED)]);
+        assert_eq!(output.calls.unwrap().usdc_call_transfer_froms, vec![transfer_from(OTHER, OTHER, WATCHED)]);
+    }
+
+    #[test]
+    fn drops_other_events() {
+        let events = contract::Events {
+            usdc_mints: vec![contract::UsdcMint { minter: WATCHED.to_vec(), to: WATCHED.to_vec(), ..Default::default() }],
+            usdc_blacklisteds: vec![contract::UsdcBlacklisted { u_account: WATCHED.to_vec(), ..Default::default() }],
+            ..Default::default()
+        };
+
+        assert_eq!(retain(events, contract::Calls::default()).events.unwrap(), contract::Events::default());
+    }
+}